@@ -0,0 +1,125 @@
+//! Compares `pf::a_star` with `pf::a_star_heap`, run with `cargo bench`
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use adventofcode2022::pf;
+
+const SIZE: usize = 80;
+const RUNS: usize = 20;
+
+/// Costs from 1 to 9, scattered with a linear congruential generator
+fn costs() -> Vec<Vec<usize>> {
+    let mut seed: u64 = 2022;
+    (0..SIZE)
+        .map(|_| {
+            (0..SIZE)
+                .map(|_| {
+                    seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                    (seed >> 33) as usize % 9 + 1
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn heur(&(i, j): &(usize, usize)) -> usize {
+    (SIZE - 1 - i) + (SIZE - 1 - j)
+}
+
+fn nexts(costs: &[Vec<usize>], pos: &(usize, usize)) -> HashSet<((usize, usize), usize)> {
+    pf::neighbours_usize(pos, Some(SIZE), Some(SIZE))
+        .into_iter()
+        .map(|(i, j)| ((i, j), costs[i][j]))
+        .collect()
+}
+
+/// A position dragging along a set of names, like Day 16's states
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Big {
+    pos: (usize, usize),
+    names: BTreeSet<String>,
+}
+
+fn big_nexts(costs: &[Vec<usize>], big: &Big) -> HashSet<(Big, usize)> {
+    nexts(costs, &big.pos)
+        .into_iter()
+        .map(|(pos, cost)| {
+            let names = big.names.clone();
+            (Big { pos, names }, cost)
+        })
+        .collect()
+}
+
+fn big_start() -> HashSet<Big> {
+    HashSet::from([Big {
+        pos: (0, 0),
+        names: ["AA", "BB", "CC", "DD", "EE", "FF", "GG", "HH", "II", "JJ"]
+            .map(String::from)
+            .into(),
+    }])
+}
+
+/// Runs `search` `RUNS` times and prints the min/median/max time it took
+fn bench(name: &str, search: impl Fn() -> Option<usize>) -> Option<usize> {
+    let mut times: Vec<Duration> = vec![];
+    let mut cost = None;
+
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        cost = black_box(search());
+        times.push(start.elapsed());
+    }
+    times.sort();
+
+    println!(
+        "{name:<22} │ {:>10} │ {:>10} │ {:>10}",
+        format!("{:.2?}", times[0]),
+        format!("{:.2?}", times[times.len() / 2]),
+        format!("{:.2?}", times[times.len() - 1]),
+    );
+
+    cost
+}
+
+fn main() {
+    let costs = costs();
+    let end = |pos: &(usize, usize)| *pos == (SIZE - 1, SIZE - 1);
+
+    println!(
+        "{:<22} │ {:>10} │ {:>10} │ {:>10}",
+        "", "Min", "Median", "Max"
+    );
+
+    let grid = bench("grid_a_star", || {
+        pf::a_star(HashSet::from([(0, 0)]), end, |pos| nexts(&costs, pos), heur)
+            .map(|(_, cost)| cost)
+    });
+    let grid_heap = bench("grid_a_star_heap", || {
+        pf::a_star_heap([(0, 0)], end, |pos| nexts(&costs, pos), heur).map(|(_, cost)| cost)
+    });
+    assert_eq!(grid, grid_heap);
+
+    let big = bench("big_states_a_star", || {
+        pf::a_star(
+            big_start(),
+            |big| end(&big.pos),
+            |big| big_nexts(&costs, big),
+            |big| heur(&big.pos),
+        )
+        .map(|(_, cost)| cost)
+    });
+    let big_heap = bench("big_states_a_star_heap", || {
+        pf::a_star_heap(
+            big_start(),
+            |big| end(&big.pos),
+            |big| big_nexts(&costs, big),
+            |big| heur(&big.pos),
+        )
+        .map(|(_, cost)| cost)
+    });
+    assert_eq!(big, big_heap);
+}
@@ -0,0 +1,202 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use crate::Part;
+
+/// Known answers to each day's puzzle, stored in a small subset of TOML:
+///
+/// ```toml
+/// [day1]
+/// part1 = "67622"
+/// part2 = "201491"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<usize, BTreeMap<Part, String>>,
+}
+
+impl Answers {
+    /// Loads the answers file at `path`, which is treated as empty if it doesn't exist yet
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .with_context(|| format!("Couldn't parse answers from '{path}'")),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Couldn't read answers from '{path}'")),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("Couldn't write answers to '{path}'"))
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.days.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    /// Records an answer, returning the one it replaced (if any)
+    pub fn insert(&mut self, day: usize, part: Part, answer: String) -> Option<String> {
+        self.days.entry(day).or_default().insert(part, answer)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut day = None;
+
+        for (line_idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix("[day").and_then(|l| l.strip_suffix(']')) {
+                day = Some(
+                    header
+                        .parse()
+                        .with_context(|| format!("Line {}: invalid day '{line}'", line_idx + 1))?,
+                );
+                continue;
+            }
+
+            let Some(day) = day else {
+                bail!(
+                    "Line {}: answer found before any [dayN] header",
+                    line_idx + 1
+                );
+            };
+
+            let (key, value) = line
+                .split_once('=')
+                .with_context(|| format!("Line {}: expected 'partN = \"...\"'", line_idx + 1))?;
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => bail!("Line {}: unexpected key '{key}'", line_idx + 1),
+            };
+
+            let value = unescape(value.trim()).with_context(|| {
+                format!("Line {}: invalid string {}", line_idx + 1, value.trim())
+            })?;
+
+            answers.insert(day, part, value);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (day, parts)) in self.days.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "[day{day}]")?;
+            for (part, answer) in parts {
+                writeln!(f, "part{part} = {}", escape(answer))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Quotes `s` as a TOML basic string, which is also a valid JSON string
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::from('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+pub(crate) fn unescape(s: &str) -> anyhow::Result<String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .context("Strings should be enclosed in double quotes")?;
+
+    let mut unescaped = String::new();
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('"') => unescaped.push('"'),
+                Some('\\') => unescaped.push('\\'),
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some('t') => unescaped.push('\t'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .filter(|_| hex.len() == 4)
+                        .and_then(char::from_u32)
+                        .with_context(|| format!("Invalid escape sequence '\\u{hex}'"))?;
+                    unescaped.push(c);
+                }
+                other => bail!("Unsupported escape sequence '\\{}'", other.unwrap_or(' ')),
+            }
+        } else if c == '"' {
+            bail!("Unescaped '\"' in string");
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, "67622".to_string());
+        answers.insert(10, Part::Two, "\n#..#\n\"#\\\"\n".to_string());
+        answers.insert(5, Part::Two, "RWLWGJGFD".to_string());
+
+        let text = answers.to_string();
+        assert_eq!(
+            text,
+            "[day1]\npart1 = \"67622\"\n\n[day5]\npart2 = \"RWLWGJGFD\"\n\n[day10]\npart2 = \"\\n#..#\\n\\\"#\\\\\\\"\\n\"\n"
+        );
+        assert_eq!(text.parse::<Answers>().unwrap(), answers);
+
+        let bell = "ding\u{7}";
+        assert_eq!(escape(bell), "\"ding\\u0007\"");
+        assert_eq!(unescape(&escape(bell)).unwrap(), bell);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("part1 = \"1\"".parse::<Answers>().is_err());
+        assert!("[day1]\npart3 = \"1\"".parse::<Answers>().is_err());
+        assert!("[day1]\npart1 = 1".parse::<Answers>().is_err());
+        assert!("[dayX]".parse::<Answers>().is_err());
+    }
+}
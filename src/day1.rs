@@ -1,30 +1,12 @@
 use anyhow::Context;
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 pub struct Day1 {
     calories_per_reindeer: Vec<Vec<u32>>,
 }
 
 impl Day1 {
-    pub fn init() -> anyhow::Result<Self> {
-        let mut calories_per_reindeer = vec![];
-        let mut reindeer = vec![];
-        for line in std::fs::read_to_string("res/day1.txt")?.split('\n') {
-            if line.is_empty() {
-                calories_per_reindeer.push(std::mem::take(&mut reindeer));
-            } else {
-                reindeer.push(line.parse::<u32>()?);
-            }
-        }
-
-        calories_per_reindeer.sort_by_key(|r| r.iter().sum::<u32>());
-
-        Ok(Self {
-            calories_per_reindeer,
-        })
-    }
-
     pub fn part1(&self) -> anyhow::Result<u32> {
         self.calories_per_reindeer
             .iter()
@@ -46,9 +28,40 @@ impl Day1 {
 
 impl Day for Day1 {
     const NAME: &'static str = "Day 1: Calorie Counting 🍟 🧮";
+    const INPUT: &'static str = "res/day1.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day1_1.txt",
+        part1: Some("24000"),
+        part2: Some("45000"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let mut calories_per_reindeer = vec![];
+        let mut reindeer = vec![];
+        let input = Input::read(path)?;
+        for line in input.text().split('\n') {
+            if line.is_empty() {
+                calories_per_reindeer.push(std::mem::take(&mut reindeer));
+            } else {
+                reindeer.push(input.parse::<u32>(line, "a number of calories")?);
+            }
+        }
+        if !reindeer.is_empty() {
+            calories_per_reindeer.push(reindeer);
+        }
+
+        calories_per_reindeer.sort_by_key(|r| r.iter().sum::<u32>());
+
+        Ok(Self {
+            calories_per_reindeer,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::init()?;
-        Ok((day.part1()?.to_string(), day.part2()?.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
     }
 }
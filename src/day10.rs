@@ -2,8 +2,9 @@ use std::{collections::VecDeque, str::FromStr};
 
 use anyhow::bail;
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
+#[derive(Clone)]
 pub struct Day10 {
     reg_x: i32,
     active: Option<(usize, Op)>,
@@ -12,19 +13,7 @@ pub struct Day10 {
 }
 
 impl Day10 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        Ok(Self {
-            reg_x: 1,
-            active: None,
-            cycle: 1,
-            ops: std::fs::read_to_string(path)?
-                .lines()
-                .map(Op::from_str)
-                .collect::<Result<VecDeque<_>, _>>()?,
-        })
-    }
-
-    fn both_parts(&mut self) -> (i32, String) {
+    pub fn both_parts(&mut self) -> (i32, String) {
         let mut check = 20;
         let mut strength = 0;
         let mut screen = String::new();
@@ -48,7 +37,7 @@ impl Day10 {
                 screen.push('.');
             }
 
-            if self.cycle % 40 == 0 {
+            if self.cycle.is_multiple_of(40) {
                 screen.push('\n');
             }
 
@@ -72,15 +61,45 @@ impl Day10 {
 
 impl Day for Day10 {
     const NAME: &'static str = "Day 10: Cathode-Ray Tube 💡 📡";
+    const INPUT: &'static str = "res/day10.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day10_1.txt",
+        part1: Some("13140"),
+        part2: Some(concat!(
+            "\n",
+            "##..##..##..##..##..##..##..##..##..##..\n",
+            "###...###...###...###...###...###...###.\n",
+            "####....####....####....####....####....\n",
+            "#####.....#####.....#####.....#####.....\n",
+            "######......######......######......####\n",
+            "#######.......#######.......#######.....\n",
+        )),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let mut day = Self::load("res/day10.txt")?;
-        let (part1, part2) = day.both_parts();
+        Ok(Self {
+            reg_x: 1,
+            active: None,
+            cycle: 1,
+            ops: input
+                .lines()
+                .map(|line| input.parse(line, "'noop' or 'addx N'"))
+                .collect::<Result<VecDeque<_>, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::both_parts(&mut self.clone()).0.to_string())
+    }
 
-        Ok((part1.to_string(), format!("\n{part2}")))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(format!("\n{}", Self::both_parts(&mut self.clone()).1))
     }
 }
 
+#[derive(Clone)]
 enum Op {
     Noop,
     Addx(i32),
@@ -2,7 +2,10 @@ use std::str::FromStr;
 
 use anyhow::{bail, Context};
 
-use crate::Day;
+use crate::{
+    input::{Input, InputError},
+    Day, Example,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day11 {
@@ -10,16 +13,7 @@ pub struct Day11 {
 }
 
 impl Day11 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        Ok(Self {
-            monkeys: std::fs::read_to_string(path)?
-                .split("\n\n")
-                .map(Monkey::from_str)
-                .collect::<Result<_, _>>()?,
-        })
-    }
-
-    fn part1(&mut self) -> u64 {
+    pub fn part1(&mut self) -> u64 {
         let mut inspections = vec![0; self.monkeys.len()];
 
         for _ in 0..20 {
@@ -46,7 +40,7 @@ impl Day11 {
         inspections[inspections.len() - 1] * inspections[inspections.len() - 2]
     }
 
-    fn part2(&mut self) -> usize {
+    pub fn part2(&mut self) -> usize {
         let test_product = self.monkeys.iter().map(|m| m.divisor).product::<u64>();
 
         let mut inspections = vec![0; self.monkeys.len()];
@@ -78,11 +72,31 @@ impl Day11 {
 
 impl Day for Day11 {
     const NAME: &'static str = "Day 11: Monkey in the Middle 🏈🐒";
+    const INPUT: &'static str = "res/day11.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day11_1.txt",
+        part1: Some("10605"),
+        part2: Some("2713310158"),
+    }];
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let mut day = Self::load("res/day11.txt")?;
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
 
-        Ok((day.clone().part1().to_string(), day.part2().to_string()))
+        Ok(Self {
+            monkeys: input
+                .text()
+                .split("\n\n")
+                .map(|block| Monkey::parse(&input, block))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(&mut self.clone()).to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(&mut self.clone()).to_string())
     }
 }
 
@@ -95,26 +109,32 @@ struct Monkey {
     throw_false: usize,
 }
 
-impl FromStr for Monkey {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.lines();
-
-        let id = sscanf::scanf!(
-            lines.next().context("Not enough lined")?,
-            "Monkey {}:",
-            usize
-        )
-        .map_err(|e| anyhow::format_err!("Couldn't parse monkey id: {e}"))?;
-        let items = parse_items(id, lines.next().context("Not enough lines")?)?;
-        let operation = lines.next().context("Not enough lines")?.parse()?;
-        let divisor = parse_divisor(lines.next().context("Not enough lines")?)?;
-        let throw_true = parse_throw(lines.next().context("Not enough lines")?)?;
-        let throw_false = parse_throw(lines.next().context("Not enough lines")?)?;
+impl Monkey {
+    /// Parses the block of lines describing a monkey, which is a slice of `input`
+    fn parse(input: &Input, block: &str) -> Result<Self, InputError> {
+        let mut lines = block.lines();
+        let mut next = |expected: &str| {
+            lines
+                .next()
+                .ok_or_else(|| input.error(&block[block.len()..], expected))
+        };
+
+        let line = next("'Monkey N:'")?;
+        sscanf::scanf!(line, "Monkey {}:", usize)
+            .map_err(|e| input.error(line, "'Monkey N:'").because(e))?;
+
+        let items = parse_items(input, next("'Starting items: ...'")?)?;
+        let line = next("'Operation: new = ...'")?;
+        let operation = input.parse(line, "'Operation: new = old [+*] N' or 'old * old'")?;
+        let divisor = parse_after(input, next("'Test: ...'")?, "Test: divisible by ")?;
+        let throw_true = parse_after(input, next("'If true: ...'")?, "If true: throw to monkey ")?;
+        let throw_false = parse_after(
+            input,
+            next("'If false: ...'")?,
+            "If false: throw to monkey ",
+        )?;
 
         Ok(Self {
-            // id,
             items,
             operation,
             divisor,
@@ -157,9 +177,9 @@ impl FromStr for Operation {
 
         Ok(if trimmed == "old * old" {
             Self::Sqr
-        } else if let Some(v) = sscanf::scanf!(trimmed, "old + {}", u64).ok() {
+        } else if let Ok(v) = sscanf::scanf!(trimmed, "old + {}", u64) {
             Self::Add(v)
-        } else if let Some(v) = sscanf::scanf!(trimmed, "old * {}", u64).ok() {
+        } else if let Ok(v) = sscanf::scanf!(trimmed, "old * {}", u64) {
             Self::Mul(v)
         } else {
             bail!("Couldn't parse '{s}' as operation")
@@ -167,35 +187,29 @@ impl FromStr for Operation {
     }
 }
 
-fn parse_items(_initial_monkey: usize, s: &str) -> anyhow::Result<Vec<Item>> {
-    Ok(s.trim()
+fn parse_items(input: &Input, line: &str) -> Result<Vec<Item>, InputError> {
+    line.trim()
         .strip_prefix("Starting items: ")
-        .context("Unexpected item line")?
+        .ok_or_else(|| input.error(line, "'Starting items: ...'"))?
         .split(',')
         .map(|n| {
-            n.trim().parse().map(|worry| Item {
-                // initial_monkey,
-                worry,
-            })
+            input
+                .parse(n.trim(), "a worry level")
+                .map(|worry| Item { worry })
         })
-        .collect::<Result<_, _>>()?)
-}
-
-fn parse_divisor(s: &str) -> anyhow::Result<u64> {
-    Ok(s.trim()
-        .strip_prefix("Test: divisible by ")
-        .context("Unexpected divisor line")?
-        .parse()?)
+        .collect()
 }
 
-fn parse_throw(s: &str) -> anyhow::Result<usize> {
-    let trimmed = s.trim();
-
-    if let Some(s) = trimmed.strip_prefix("If true: throw to monkey ") {
-        Ok(s.parse()?)
-    } else if let Some(s) = trimmed.strip_prefix("If false: throw to monkey ") {
-        Ok(s.parse()?)
-    } else {
-        bail!("Unexpected throw line")
-    }
+/// Parses what comes after `prefix` in `line`
+fn parse_after<T>(input: &Input, line: &str, prefix: &str) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let n = line
+        .trim()
+        .strip_prefix(prefix)
+        .ok_or_else(|| input.error(line, format!("'{prefix}N'")))?;
+
+    input.parse(n, "a number")
 }
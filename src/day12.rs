@@ -1,107 +1,94 @@
-use std::collections::HashSet;
-
 use anyhow::Context;
 
-use crate::Day;
+use crate::{
+    grid::Grid,
+    input::{Input, InputError},
+    pf, Day, Example,
+};
 
 pub struct Day12 {
     start: (usize, usize),
     end: (usize, usize),
-    map: Vec<Vec<char>>,
+    map: Grid<char>,
 }
 
 impl Day12 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        let mut start = None;
-        let mut end = None;
-        let mut map = vec![];
-
-        for (i, line) in std::fs::read_to_string(path)?.lines().enumerate() {
-            let mut row = vec![];
-
-            for (j, c) in line.chars().enumerate() {
-                if c == 'S' {
-                    start = Some((i, j));
-                    row.push('a');
-                } else if c == 'E' {
-                    end = Some((i, j));
-                    row.push('z');
-                } else {
-                    row.push(c);
-                }
-            }
-
-            map.push(row);
-        }
-
-        Ok(Self {
-            start: start.context("Couldn't find starting point")?,
-            end: end.context("Couldn't find ending point")?,
-            map,
-        })
-    }
-
-    fn part1(&self) -> anyhow::Result<usize> {
-        self.find_shortest_path(HashSet::from([self.start]))
+    pub fn part1(&self) -> anyhow::Result<usize> {
+        pf::bfs([self.start], |&pos| self.nexts(pos, false))
+            .get(&self.end)
             .context("Couldn't find path")
-            .map(|(_, cost)| cost)
     }
 
-    fn part2(&self) -> anyhow::Result<usize> {
-        let mut starts = HashSet::new();
-
-        for i in 0..self.map.len() {
-            for j in 0..self.map[0].len() {
-                if self.map[i][j] == 'a' {
-                    starts.insert((i, j));
-                }
-            }
-        }
-
-        self.find_shortest_path(starts)
+    pub fn part2(&self) -> anyhow::Result<usize> {
+        // Walking down from the end reaches every square at once
+        pf::bfs([self.end], |&pos| self.nexts(pos, true))
+            .dists
+            .into_iter()
+            .filter(|&(pos, _)| self.map[pos] == 'a')
+            .map(|(_, steps)| steps)
+            .min()
             .context("Couldn't find path")
-            .map(|(_, len)| len)
     }
 
-    fn find_shortest_path(
+    /// Squares that can be reached in one step from `pos`, or that can reach it if `downhill`
+    fn nexts(
         &self,
-        from: HashSet<(usize, usize)>,
-    ) -> Option<(Vec<(usize, usize)>, usize)> {
-        let heuristic =
-            |curr: &(usize, usize)| self.end.0.abs_diff(curr.0) + self.end.1.abs_diff(curr.1);
-
-        crate::pf::a_star(
-            from,
-            |pos| pos == &self.end,
-            |&(i, j)| {
-                let neighbours = crate::pf::neighbours_usize(
-                    &(i, j),
-                    Some(self.map.len()),
-                    Some(self.map[0].len()),
-                );
-
-                let curr_height = self.map[i][j] as usize;
-                let mut nexts: HashSet<((usize, usize), usize)> = HashSet::new();
-                for (n_i, n_j) in neighbours.into_iter() {
-                    let height: char = self.map[n_i][n_j];
-                    if (height as usize).saturating_sub(curr_height) <= 1 {
-                        nexts.insert(((n_i, n_j), 1));
-                    }
-                }
-
-                nexts
-            },
-            heuristic,
-        )
+        pos: (usize, usize),
+        downhill: bool,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.map.neighbours(pos).filter(move |&next| {
+            let (from, to) = (self.map[pos] as usize, self.map[next] as usize);
+            if downhill {
+                from.saturating_sub(to) <= 1
+            } else {
+                to.saturating_sub(from) <= 1
+            }
+        })
     }
 }
 
 impl Day for Day12 {
     const NAME: &'static str = "Day 12: Hill Climbing Algorithm 🥾🏔";
+    const INPUT: &'static str = "res/day12.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day12_1.txt",
+        part1: Some("31"),
+        part2: Some("29"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+        let mut map = Grid::parse(
+            &input,
+            input.text(),
+            "a height from 'a' to 'z', 'S' or 'E'",
+            |c| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c),
+        )?;
+
+        // Replaces the marker with its height
+        let mut find = |marker, height, expected| {
+            let pos = map
+                .iter()
+                .find(|&(_, c)| *c == marker)
+                .map(|(pos, _)| pos)
+                .ok_or_else(|| input.error(input.end(), expected))?;
+            map[pos] = height;
+
+            Ok::<_, InputError>(pos)
+        };
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day12.txt")?;
+        Ok(Self {
+            start: find('S', 'a', "a starting point 'S'")?,
+            end: find('E', 'z', "an ending point 'E'")?,
+            map,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
 
-        Ok((day.part1()?.to_string(), day.part2()?.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
     }
 }
@@ -1,25 +1,19 @@
-use std::{cmp::Ordering, iter::Peekable, str::Chars};
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
 
-use anyhow::{bail, Context};
+use anyhow::Context;
 
-use crate::Day;
+use crate::{
+    input::{char_at, Input, InputError},
+    Day, Example,
+};
 
+#[derive(Clone)]
 pub struct Day13 {
     packets: Vec<Packet>,
 }
 
 impl Day13 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        Ok(Self {
-            packets: std::fs::read_to_string(path)?
-                .lines()
-                .filter(|l| !l.is_empty())
-                .map(|line| Packet::parse(&mut line.chars().peekable()))
-                .collect::<anyhow::Result<Vec<_>>>()?,
-        })
-    }
-
-    fn part1(&self) -> anyhow::Result<usize> {
+    pub fn part1(&self) -> anyhow::Result<usize> {
         Ok(self
             .packets
             .chunks(2)
@@ -29,7 +23,7 @@ impl Day13 {
             .sum())
     }
 
-    fn part2(&mut self) -> anyhow::Result<usize> {
+    pub fn part2(&mut self) -> anyhow::Result<usize> {
         let divider_2 = Packet::List(vec![Packet::List(vec![Packet::Int(2)])]);
         let divider_6 = Packet::List(vec![Packet::List(vec![Packet::Int(6)])]);
 
@@ -58,11 +52,31 @@ impl Day13 {
 
 impl Day for Day13 {
     const NAME: &'static str = "Day 13: Distress Signal 🛟 📻";
+    const INPUT: &'static str = "res/day13.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day13_1.txt",
+        part1: Some("13"),
+        part2: Some("140"),
+    }];
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let mut day = Self::load("res/day13.txt")?;
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
 
-        Ok((day.part1()?.to_string(), day.part2()?.to_string()))
+        Ok(Self {
+            packets: input
+                .lines()
+                .filter(|l| !l.is_empty())
+                .map(|line| Packet::parse(&input, line, &mut line.char_indices().peekable()))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(&mut self.clone())?.to_string())
     }
 }
 
@@ -73,32 +87,31 @@ enum Packet {
 }
 
 impl Packet {
-    fn parse(s: &mut Peekable<Chars>) -> anyhow::Result<Self> {
+    /// Parses the packet starting at `s`, which iterates over `line`, a slice of `input`
+    fn parse(input: &Input, line: &str, s: &mut Peekable<CharIndices>) -> Result<Self, InputError> {
         match s.next() {
-            Some('[') => {
+            Some((_, '[')) => {
                 let mut subpackets = vec![];
 
-                while s.peek() != Some(&']') {
-                    subpackets.push(Self::parse(s)?);
-                    if s.peek() == Some(&',') {
-                        let _ = s.next();
-                    }
+                while s.peek().map(|&(_, c)| c) != Some(']') {
+                    subpackets.push(Self::parse(input, line, s)?);
+                    s.next_if(|&(_, c)| c == ',');
                 }
 
                 let _ = s.next();
 
                 Ok(Self::List(subpackets))
             }
-            Some(digit @ '0'..='9') => {
-                let mut num_str = digit.to_string();
-                while s.peek().map(|c| c.is_alphanumeric()).unwrap_or_default() {
-                    num_str.push(s.next().context("Next should return a numeric")?);
+            Some((start, '0'..='9')) => {
+                let mut end = start + 1;
+                while let Some((j, _)) = s.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = j + 1;
                 }
-                Ok(Self::Int(
-                    num_str.parse().context("Couldn't parse integer")?,
-                ))
+
+                Ok(Self::Int(input.parse(&line[start..end], "an integer")?))
             }
-            _ => bail!("Couldn't parse packet"),
+            Some((j, _)) => Err(input.error(char_at(line, j), "'[' or an integer")),
+            None => Err(input.error(&line[line.len()..], "'[' or an integer")),
         }
     }
 }
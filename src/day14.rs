@@ -1,157 +1,181 @@
-use std::collections::HashSet;
-
-use anyhow::Context;
-
-use crate::Day;
+use std::iter;
+
+use crate::{
+    grid::{Grid, SparseGrid},
+    input::Input,
+    Day, Example,
+};
+
+/// Where the sand comes from
+const SOURCE_J: usize = 500;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+    /// Only drawn, sand falls through it
+    Source,
+}
 
 #[derive(Clone)]
-/// FIXME: slow
 pub struct Day14 {
-    cave: HashSet<(usize, usize)>,
-    max_i: usize,
-    max_j: usize,
-    min_j: usize,
+    /// From the top down to the floor (excluded), wide enough for the sand to pile up against it
+    cave: Grid<Tile>,
+    /// Column of the leftmost tiles
+    left: usize,
 }
 
 impl Day14 {
-    fn _print_cave(&self) {
-        for i in 0..=self.max_i + 2 {
-            for j in self.min_j - 10..=self.max_j + 10 {
-                if i == 0 && j == 500 {
-                    print!("+");
-                } else if self.cave.contains(&(i, j)) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
+    /// Draws the rocks, the sand and its source like the puzzle does, around them only
+    pub fn render(&self) -> String {
+        let mut tiles: SparseGrid<(isize, isize), Tile> = self
+            .cave
+            .iter()
+            .filter(|(_, tile)| **tile != Tile::Air)
+            .map(|((i, j), tile)| ((i as isize, (j + self.left) as isize), *tile))
+            .collect();
+
+        let source = (0, SOURCE_J as isize);
+        if !tiles.contains(source) {
+            tiles.insert(source, Tile::Source);
+        }
+
+        tiles.render(|tile| match tile {
+            None | Some(Tile::Air) => '.',
+            Some(Tile::Rock) => '#',
+            Some(Tile::Sand) => 'o',
+            Some(Tile::Source) => '+',
+        })
+    }
+
+    /// Lets a unit of sand fall from the source until it comes to rest, returning where it does,
+    /// or nothing if the source is blocked
+    fn drop_sand(&mut self) -> Option<(usize, usize)> {
+        let mut pos = (0, SOURCE_J - self.left);
+        if self.cave[pos] != Tile::Air {
+            return None;
+        }
+
+        // Down, then down-left, then down-right, until the floor
+        while let Some(below) = [0, -1, 1]
+            .into_iter()
+            .filter_map(|dj| self.cave.offset(pos, (1, dj)))
+            .find(|&below| self.cave[below] == Tile::Air)
+        {
+            pos = below;
+        }
+
+        self.cave[pos] = Tile::Sand;
+        Some(pos)
+    }
+
+    pub fn part1(&mut self) -> usize {
+        let abyss = self.cave.height() - 1;
+        let mut cnt = 0;
+
+        while let Some(pos) = self.drop_sand() {
+            // Sand resting on the floor went past every rock, it would have fallen into the abyss
+            if pos.0 == abyss {
+                self.cave[pos] = Tile::Air;
+                break;
             }
-            println!();
+            cnt += 1;
         }
-        println!()
+
+        cnt
+    }
+
+    pub fn part2(&mut self) -> usize {
+        iter::from_fn(|| self.drop_sand()).count()
     }
+}
+
+impl Day for Day14 {
+    const NAME: &'static str = "Day 14: Regolith Reservoir ⏳ ⚠️";
+    const INPUT: &'static str = "res/day14.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day14_1.txt",
+        part1: Some("24"),
+        part2: Some("93"),
+    }];
 
     fn load(path: &str) -> anyhow::Result<Self> {
-        let mut cave = HashSet::new();
-        let mut max_i = 0;
-        let mut max_j = 0;
-        let mut min_j = usize::MAX;
+        let mut rocks = SparseGrid::new();
 
-        for line in std::fs::read_to_string(path)?.lines() {
+        let input = Input::read(path)?;
+        for line in input.lines() {
             let mut point = None;
             for point_str in line.split(" -> ") {
-                let mut split = point_str.split(',');
-                let j = split.next().context("Unexpected point format")?.parse()?;
-                let i = split.next().context("Unexpected point format")?.parse()?;
+                let (j, i) = point_str
+                    .split_once(',')
+                    .ok_or_else(|| input.error(point_str, "a point, like '498,4'"))?;
+                let j: usize = input.parse(j, "a column")?;
+                let i: usize = input.parse(i, "a row")?;
+                let (i, j) = (i as isize, j as isize);
 
                 if let Some((prev_i, prev_j)) = point {
-                    if prev_i == i {
-                        let start_j = std::cmp::min(prev_j, j);
-                        let end_j = std::cmp::max(prev_j, j);
-                        for j in start_j..=end_j {
-                            cave.insert((i, j));
-                        }
-                    } else {
-                        let start_i = std::cmp::min(prev_i, i);
-                        let end_i = std::cmp::max(prev_i, i);
-                        for i in start_i..=end_i {
-                            cave.insert((i, j));
+                    for i in std::cmp::min(prev_i, i)..=std::cmp::max(prev_i, i) {
+                        for j in std::cmp::min(prev_j, j)..=std::cmp::max(prev_j, j) {
+                            rocks.insert((i, j), ());
                         }
                     }
                 }
 
-                max_i = std::cmp::max(max_i, i);
-                max_j = std::cmp::max(max_j, j);
-                min_j = std::cmp::min(min_j, j);
                 point = Some((i, j));
             }
         }
 
-        Ok(Self {
-            cave,
-            max_i,
-            max_j,
-            min_j,
-        })
-    }
+        let Some(((_, min_j), (max_i, max_j))) = rocks.bounds() else {
+            return Err(input.error(input.end(), "at least a path of rock").into());
+        };
+        let (max_i, max_j, min_j) = (max_i as usize, max_j as usize, min_j as usize);
 
-    fn part1(&mut self) -> usize {
-        'sands: for sand in 0.. {
-            let mut i = 0;
-            let mut j = 500;
-
-            loop {
-                let below = i + 1;
-
-                if !self.cave.contains(&(below, j)) {
-                    // DOWN
-                    i = below;
-                } else if !self.cave.contains(&(below, j - 1)) {
-                    // LEFT
-                    i = below;
-                    j -= 1;
-                } else if !self.cave.contains(&(below, j + 1)) {
-                    // RIGHT
-                    i = below;
-                    j += 1;
-                } else {
-                    self.cave.insert((i, j));
-                    continue 'sands;
-                }
+        // The sand piles up as a triangle on the floor
+        let floor = max_i + 2;
+        let left = std::cmp::min(min_j, SOURCE_J.saturating_sub(floor));
+        let right = std::cmp::max(max_j, SOURCE_J + floor);
+        let mut cave = Grid::new(floor, right - left + 1, Tile::Air);
 
-                if j < self.min_j || j > self.max_j {
-                    return sand;
-                }
-            }
+        for ((i, j), _) in rocks.iter() {
+            cave[(i as usize, j as usize - left)] = Tile::Rock;
         }
 
-        unreachable!("wat");
+        Ok(Self { cave, left })
     }
 
-    fn part2(&mut self) -> usize {
-        'sands: for sand in 0.. {
-            let mut i = 0;
-            let mut j = 500;
-
-            if self.cave.contains(&(i, j)) {
-                return sand;
-            }
-
-            loop {
-                let below = i + 1;
-
-                if !self.cave.contains(&(below, j)) {
-                    // DOWN
-                    i = below;
-                } else if !self.cave.contains(&(below, j - 1)) {
-                    // LEFT
-                    i = below;
-                    j -= 1;
-                } else if !self.cave.contains(&(below, j + 1)) {
-                    // RIGHT
-                    i = below;
-                    j += 1;
-                } else {
-                    self.cave.insert((i, j));
-                    continue 'sands;
-                }
-
-                if i == self.max_i + 1 {
-                    self.cave.insert((i, j));
-                    continue 'sands;
-                }
-            }
-        }
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(&mut self.clone()).to_string())
+    }
 
-        unreachable!("wat");
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(&mut self.clone()).to_string())
     }
 }
 
-impl Day for Day14 {
-    const NAME: &'static str = "Day 14: Regolith Reservoir ⏳ ⚠️";
-
-    fn solve() -> anyhow::Result<(String, String)> {
-        let mut day = Self::load("res/day14.txt")?;
-
-        Ok((day.clone().part1().to_string(), day.part2().to_string()))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut day = Day14::load("res/examples/day14_1.txt").unwrap();
+        Day14::part1(&mut day);
+
+        assert_eq!(
+            day.render(),
+            "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
     }
 }
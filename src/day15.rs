@@ -1,61 +1,49 @@
-use std::collections::{HashMap, HashSet};
-
 use anyhow::bail;
 
-use crate::Day;
+use crate::{grid::SparseGrid, input::Input, Day, Example};
+
+#[derive(Debug)]
+enum Tile {
+    /// Along with the distance to its closest beacon
+    Sensor(usize),
+    Beacon,
+}
 
 #[derive(Debug)]
 pub struct Day15 {
-    readings: HashMap<(isize, isize), usize>,
-    beacons: HashSet<(isize, isize)>,
+    map: SparseGrid<(isize, isize), Tile>,
 }
 
 impl Day15 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        let mut beacons = HashSet::new();
-        let mut readings = HashMap::new();
-
-        for line in std::fs::read_to_string(path)?.lines() {
-            let (a, b, c, d) = sscanf::sscanf!(
-                line,
-                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
-                isize,
-                isize,
-                isize,
-                isize,
-            )
-            .map_err(|err| anyhow::format_err!("Couldn't parse line: {err}"))?;
-
-            readings.insert((b, a), b.abs_diff(d) + c.abs_diff(a));
-            beacons.insert((d, c));
-        }
-
-        Ok(Self { beacons, readings })
+    fn sensors(&self) -> impl Iterator<Item = ((isize, isize), usize)> + '_ {
+        self.map.iter().filter_map(|(pos, tile)| match tile {
+            Tile::Sensor(radius) => Some((pos, *radius)),
+            Tile::Beacon => None,
+        })
     }
 
-    fn line_exclusion(&self, line: isize) -> Vec<(isize, isize)> {
+    pub fn line_exclusion(&self, line: isize) -> Vec<(isize, isize)> {
         let mut ranges = vec![];
 
-        for (sensor, radius) in self.readings.iter() {
-            let Some(remaining_radius) = radius
-                .checked_sub(sensor.0.abs_diff(line)) else {
+        // Sensors and beacons on the line, where there can't be a missing beacon either
+        let taken: Vec<_> = self
+            .map
+            .iter()
+            .filter(|(pos, _)| pos.0 == line)
+            .map(|((_, j), _)| (j, j))
+            .collect();
 
-                    continue;
-                };
+        for (sensor, radius) in self.sensors() {
+            let Some(remaining_radius) = radius.checked_sub(sensor.0.abs_diff(line)) else {
+                continue;
+            };
 
             let mut new_ranges = vec![(
                 sensor.1 - remaining_radius as isize,
                 sensor.1 + remaining_radius as isize,
             )];
 
-            for range in self
-                .beacons
-                .iter()
-                .chain(self.readings.keys())
-                .filter(|b| b.0 == line)
-                .map(|(_, j)| (*j, *j))
-                .chain(ranges.iter().cloned())
-            {
+            for &range in taken.iter().chain(ranges.iter()) {
                 let mut i = 0;
                 while i < new_ranges.len() {
                     let subranges = subtract_range(new_ranges[i], range);
@@ -69,7 +57,7 @@ impl Day15 {
                 }
             }
 
-            ranges.extend(new_ranges.into_iter());
+            ranges.extend(new_ranges);
         }
 
         ranges.sort();
@@ -77,7 +65,7 @@ impl Day15 {
         ranges
     }
 
-    fn part1(&self, line: isize) -> isize {
+    pub fn part1(&self, line: isize) -> isize {
         self.line_exclusion(line)
             .iter()
             .map(|(min, max)| max - min + 1)
@@ -85,15 +73,12 @@ impl Day15 {
     }
 
     // FIXME: slow
-    fn part2(&self, max: isize) -> anyhow::Result<isize> {
+    pub fn part2(&self, max: isize) -> anyhow::Result<isize> {
         for line in 0..=max {
             let ranges = self.line_exclusion(line);
 
             for pair in ranges.windows(2) {
-                if pair[0].1 + 2 == pair[1].0
-                    && !self.readings.contains_key(&(line, pair[0].1 + 1))
-                    && !self.beacons.contains(&(line, pair[0].1 + 1))
-                {
+                if pair[0].1 + 2 == pair[1].0 && !self.map.contains((line, pair[0].1 + 1)) {
                     return Ok((pair[0].1 + 1) * 4000000 + line);
                 }
             }
@@ -105,14 +90,46 @@ impl Day15 {
 
 impl Day for Day15 {
     const NAME: &'static str = "Day 15: Beacon Exclusion Zone 📡 🚫";
+    const INPUT: &'static str = "res/day15.txt";
+    /// The example uses another row and bound than the actual puzzle, see the tests
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day15_1.txt",
+        part1: None,
+        part2: None,
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let mut map = SparseGrid::new();
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day15.txt")?;
+        let input = Input::read(path)?;
+        for line in input.lines() {
+            let (a, b, c, d) = sscanf::sscanf!(
+                line,
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                isize,
+                isize,
+                isize,
+                isize,
+            )
+            .map_err(|err| {
+                input
+                    .error(line, "'Sensor at x=X, y=Y: closest beacon is at x=X, y=Y'")
+                    .because(err)
+            })?;
+
+            map.insert((b, a), Tile::Sensor(b.abs_diff(d) + c.abs_diff(a)));
+            map.insert((d, c), Tile::Beacon);
+        }
 
-        Ok((
-            day.part1(2_000_000).to_string(),
-            day.part2(4_000_000)?.to_string(),
-        ))
+        Ok(Self { map })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self, 2_000_000).to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self, 4_000_000)?.to_string())
     }
 }
 
@@ -141,3 +158,16 @@ fn subtract_range(
 
     subranges
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let day = Day15::load("res/examples/day15_1.txt").unwrap();
+
+        assert_eq!(day.part1(10), 26);
+        assert_eq!(day.part2(20).unwrap(), 56000011);
+    }
+}
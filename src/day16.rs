@@ -2,7 +2,7 @@ use std::collections::{BTreeSet, HashMap, HashSet};
 
 use anyhow::Context;
 
-use crate::{pf, Day};
+use crate::{input::Input, pf, Day, Example};
 
 #[derive(Debug)]
 pub struct Day16 {
@@ -10,31 +10,7 @@ pub struct Day16 {
 }
 
 impl Day16 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        let mut valves = HashMap::new();
-
-        for (line_idx, line) in std::fs::read_to_string(path)?.lines().enumerate() {
-            let (valve_id, rate, _, _, _, tunnels) = sscanf::scanf!(
-                line,
-                "Valve {} has flow rate={}; tunnel{:/[s]?/} lead{:/[s]?/} to valve{:/[s]?/} {}",
-                String,
-                usize,
-                String,
-                String,
-                String,
-                String,
-            )
-            .map_err(|e| anyhow::format_err!("Line {}: {e}", line_idx + 1))?;
-
-            let tunnels = tunnels.split(", ").map(|s| s.to_string()).collect();
-
-            valves.insert(valve_id, Valve { tunnels, rate });
-        }
-
-        Ok(Self { valves })
-    }
-
-    fn solve(&self, elephant: bool) -> anyhow::Result<usize> {
+    pub fn solve(&self, elephant: bool) -> anyhow::Result<usize> {
         let heur = |s: &State| {
             let mut estimation = 0;
             let mut closed_valves: BTreeSet<usize> = s
@@ -172,7 +148,7 @@ impl Day16 {
             // }
         };
 
-        let (states, _) = pf::a_star(
+        let (states, _) = pf::a_star_heap(
             HashSet::from([State {
                 closed_valves: self.valves.keys().cloned().collect(),
                 curr_valve: "AA".to_string(),
@@ -217,13 +193,51 @@ impl Day16 {
 
 impl Day for Day16 {
     const NAME: &'static str = "Day 16: Proboscidea Volcanium 🐘 🌋";
+    const INPUT: &'static str = "res/day16.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day16_1.txt",
+        part1: Some("1651"),
+        part2: Some("1707"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let mut valves = HashMap::new();
+
+        let input = Input::read(path)?;
+        for line in input.lines() {
+            let (valve_id, rate, _, _, _, tunnels) = sscanf::scanf!(
+                line,
+                "Valve {} has flow rate={}; tunnel{:/[s]?/} lead{:/[s]?/} to valve{:/[s]?/} {}",
+                String,
+                usize,
+                String,
+                String,
+                String,
+                String,
+            )
+            .map_err(|e| {
+                input
+                    .error(
+                        line,
+                        "'Valve AA has flow rate=N; tunnels lead to valves BB, CC'",
+                    )
+                    .because(e)
+            })?;
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day16.txt")?;
+            let tunnels = tunnels.split(", ").map(|s| s.to_string()).collect();
+
+            valves.insert(valve_id, Valve { tunnels, rate });
+        }
+
+        Ok(Self { valves })
+    }
 
-        // println!("{day:#?}");
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(self.solve(false)?.to_string())
+    }
 
-        Ok((day.solve(false)?.to_string(), day.solve(true)?.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.solve(true)?.to_string())
     }
 }
 
@@ -233,7 +247,7 @@ struct Valve {
     tunnels: HashSet<String>,
 }
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 struct State {
     curr_valve: String,
     elephant_valve: String,
@@ -244,6 +258,7 @@ struct State {
 #[cfg(test)]
 mod tests {
     use super::Day16;
+    use crate::Day;
 
     #[test]
     fn part1() {
@@ -251,12 +266,4 @@ mod tests {
 
         assert_eq!(day.solve(false).unwrap(), 1754);
     }
-
-    #[test]
-    fn examples() {
-        let day = Day16::load("res/day16_example.txt").unwrap();
-
-        assert_eq!(day.solve(false).unwrap(), 1651);
-        assert_eq!(day.solve(true).unwrap(), 1707);
-    }
 }
@@ -1,52 +1,139 @@
-use std::{
-    collections::{BTreeSet, HashSet},
-    time::Duration,
-};
-
-use anyhow::Context;
+use std::{collections::HashSet, fmt};
 
-use crate::{dir::Dir, Day};
+use crate::{
+    dir::Dir,
+    grid::Grid,
+    input::{char_at, Input},
+    pf, Day, Example,
+};
 
 pub struct Day17 {
     jets: Vec<char>,
 }
 
+/// The rocks at rest
+///
+/// ↑
+/// │
+/// i  (i,j)
+/// │
+/// └──j───→
+struct Chamber {
+    /// Upside down, row 0 being the lowest one that wasn't pruned, and the top one always empty
+    tiles: Grid<bool>,
+    /// Number of rows pruned at the bottom, they're considered as rock
+    pruned: usize,
+}
+
+impl Chamber {
+    const WIDTH: usize = 7;
+
+    fn new() -> Self {
+        Self {
+            tiles: Grid::new(1, Self::WIDTH, false),
+            pruned: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.pruned + self.tiles.height() - 1
+    }
+
+    fn is_rock(&self, (i, j): (usize, usize)) -> bool {
+        i < self.pruned || self.tiles.get((i - self.pruned, j)) == Some(&true)
+    }
+
+    fn settle(&mut self, rock: &HashSet<(usize, usize)>) {
+        for &(i, j) in rock {
+            while self.height() <= i {
+                self.tiles.push_row([false; Self::WIDTH]);
+            }
+            self.tiles[(i - self.pruned, j)] = true;
+        }
+    }
+
+    /// Drops the rows no rock can reach anymore
+    fn prune(&mut self) {
+        if let Some(edge) = self.find_edge() {
+            self.tiles.remove_rows(edge);
+            self.pruned += edge;
+        }
+    }
+
+    /// Number of rows at the bottom that can't be reached from the top, if there aren't too many
+    /// free spaces to tell
+    fn find_edge(&self) -> Option<usize> {
+        const MAX_FREE_SPACES: usize = 100;
+
+        let top = self.tiles.height() - 1;
+        if top == 0 {
+            return None;
+        }
+
+        // A free space that small can't reach further down than this, so it's enough to tell
+        // whether there are too many free spaces
+        let floor = (top - 1).saturating_sub(MAX_FREE_SPACES);
+        let free_spaces = pf::flood_fill([(top, 0)], |&pos| {
+            self.tiles
+                .neighbours(pos)
+                .filter(|&n| n.0 >= floor && !self.tiles[n])
+        });
+
+        if free_spaces.len() > MAX_FREE_SPACES {
+            return None;
+        }
+
+        let mut lowest = [usize::MAX; Self::WIDTH];
+        for (i, j) in free_spaces {
+            lowest[j] = std::cmp::min(lowest[j], i);
+        }
+
+        if lowest.contains(&usize::MAX) {
+            None
+        } else {
+            lowest.into_iter().min().map(|m| m.saturating_sub(1))
+        }
+    }
+}
+
+/// Drawn like the puzzle does, from the top down
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tiles = self.tiles.render(|&rock| if rock { '#' } else { '.' });
+
+        // Leaving out the empty top row
+        for row in tiles.lines().rev().skip(1) {
+            writeln!(f, "|{row}|")?;
+        }
+
+        if self.pruned == 0 {
+            writeln!(f, "+-------+")
+        } else {
+            writeln!(f, "|~~~~~~~|")
+        }
+    }
+}
+
 impl Day17 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        Ok(Self {
-            jets: std::fs::read_to_string(path)?.chars().collect(),
-        })
+    pub fn solve(&self, n_rocks: usize) -> anyhow::Result<usize> {
+        Ok(self.drop_rocks(n_rocks)?.height())
     }
 
-    fn solve(&self, n_rocks: usize) -> anyhow::Result<usize> {
-        // ↑
-        // │
-        // i  (i,j)
-        // │
-        // └──j───→
-        let mut tiles: BTreeSet<(usize, usize)> = BTreeSet::new();
+    /// Draws the tower after `n_rocks` rocks came to rest
+    pub fn render(&self, n_rocks: usize) -> anyhow::Result<String> {
+        Ok(self.drop_rocks(n_rocks)?.to_string())
+    }
+
+    fn drop_rocks(&self, n_rocks: usize) -> anyhow::Result<Chamber> {
+        let mut chamber = Chamber::new();
         let mut jet_idx = 0;
 
         for rock_id in 0..n_rocks {
             if rock_id % 1_000_000 == 0 {
-                if let Some(edge) = Self::find_edge(&tiles) {
-                    tiles = tiles
-                        .into_iter()
-                        .rev()
-                        .take_while(|pos| pos.0 > edge)
-                        .collect();
-
-                    // println!("Pruned tiles down to {}:", tiles.len());
-
-                    // if tiles.len() == 36 {
-                    //     Self::_print(&Default::default(), &tiles);
-                    // }
-                }
+                chamber.prune();
             }
 
-            let max_height = tiles.last().map(|(i, _)| *i + 1).unwrap_or_default();
-
-            let mut rock = Self::new_rock(rock_id, max_height);
+            let mut rock = Self::new_rock(rock_id, chamber.height());
 
             loop {
                 let jet_dir = match self.jets[jet_idx % self.jets.len()] {
@@ -55,25 +142,22 @@ impl Day17 {
                     jet => anyhow::bail!("Unexpected jet character '{jet}'"),
                 };
 
-                if let Some(shifted_rock) = Self::move_rock(&rock, &tiles, jet_dir) {
+                if let Some(shifted_rock) = Self::move_rock(&rock, &chamber, jet_dir) {
                     rock = shifted_rock;
                 }
 
                 jet_idx += 1;
 
-                if let Some(shifted_rock) = Self::move_rock(&rock, &tiles, Dir::South) {
+                if let Some(shifted_rock) = Self::move_rock(&rock, &chamber, Dir::South) {
                     rock = shifted_rock;
                 } else {
-                    tiles.extend(rock);
+                    chamber.settle(&rock);
                     break;
                 }
             }
         }
 
-        tiles
-            .pop_last()
-            .context("No tiles found")
-            .map(|(i, _)| i + 1)
+        Ok(chamber)
     }
 
     fn new_rock(id: usize, max_height: usize) -> HashSet<(usize, usize)> {
@@ -129,7 +213,7 @@ impl Day17 {
 
     fn move_rock(
         rock: &HashSet<(usize, usize)>,
-        tiles: &BTreeSet<(usize, usize)>,
+        chamber: &Chamber,
         dir: Dir,
     ) -> Option<HashSet<(usize, usize)>> {
         let mut shifted_rock = HashSet::new();
@@ -139,7 +223,7 @@ impl Day17 {
                 Dir::North => (rock_tile.0 + 1, rock_tile.1),
                 Dir::East => (
                     rock_tile.0,
-                    if rock_tile.1 < 6 {
+                    if rock_tile.1 < Chamber::WIDTH - 1 {
                         rock_tile.1 + 1
                     } else {
                         return None;
@@ -149,7 +233,7 @@ impl Day17 {
                 Dir::West => (rock_tile.0, rock_tile.1.checked_sub(1)?),
             };
 
-            if tiles.contains(&shifted) {
+            if chamber.is_rock(shifted) {
                 return None;
             } else {
                 shifted_rock.insert(shifted);
@@ -158,75 +242,71 @@ impl Day17 {
 
         Some(shifted_rock)
     }
+}
 
-    fn _print(shape: &HashSet<(usize, usize)>, tiles: &BTreeSet<(usize, usize)>) {
-        let max_i = std::cmp::max(
-            tiles.last().map(|(i, _)| *i).unwrap_or_default(),
-            shape.iter().map(|(i, _)| *i).max().unwrap_or_default(),
-        );
-
-        for i in (0..=max_i).rev().take(20) {
-            print!("│");
-            for j in 0..7 {
-                if tiles.contains(&(i, j)) || shape.contains(&(i, j)) {
-                    print!("#");
-                } else {
-                    print!(" ");
-                }
-            }
-            println!("│");
-        }
-        println!("└───────┘\n");
-
-        std::thread::sleep(Duration::from_millis(250));
-    }
-
-    fn find_edge(tiles: &BTreeSet<(usize, usize)>) -> Option<usize> {
-        const MAX_FREE_SPACES: usize = 100;
-
-        let (max_i, j) = tiles.last()?;
-
-        let mut to_visit = BTreeSet::from([(max_i + 1, *j)]);
-        let mut visited = BTreeSet::from([(max_i + 1, *j)]);
-
-        while let Some(pos) = to_visit.pop_last() {
-            for neighbour in crate::pf::neighbours_usize(&pos, Some(*max_i + 2), Some(7)) {
-                if !tiles.contains(&neighbour) && !visited.contains(&neighbour) {
-                    to_visit.insert(neighbour);
-                }
-            }
+impl Day for Day17 {
+    const NAME: &'static str = "Day 17: Pyroclastic Flow 💨 🪨";
+    const INPUT: &'static str = "res/day17.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day17_1.txt",
+        part1: Some("3068"),
+        part2: None,
+    }];
 
-            visited.insert(pos);
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+        let jets = input.text().trim_end();
 
-            if visited.len() > MAX_FREE_SPACES {
-                return None;
-            }
+        if let Some(j) = jets.find(|c| !matches!(c, '<' | '>')) {
+            return Err(input.error(char_at(jets, j), "'<' or '>'").into());
+        } else if jets.is_empty() {
+            return Err(input.error(jets, "at least one jet").into());
         }
 
-        let mut ret = [None; 7];
+        Ok(Self {
+            jets: jets.chars().collect(),
+        })
+    }
 
-        while let Some(pos) = visited.pop_first() {
-            if ret[pos.1].is_none() {
-                ret[pos.1] = Some(pos.0);
-                if ret.iter().all(Option::is_some) {
-                    return ret.into_iter().flatten().min().map(|m| m - 2);
-                }
-            }
-        }
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(self.solve(2022)?.to_string())
+    }
 
-        None
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.solve(1_000_000_000_000)?.to_string())
     }
 }
 
-impl Day for Day17 {
-    const NAME: &'static str = "Day 17: Pyroclastic Flow 💨 🪨";
-
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day17.txt")?;
-
-        Ok((
-            day.solve(2022)?.to_string(),
-            day.solve(1_000_000_000_000)?.to_string(),
-        ))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let day = Day17::load("res/examples/day17_1.txt").unwrap();
+
+        assert_eq!(
+            day.render(10).unwrap(),
+            "\
+|....#..|
+|....#..|
+|....##.|
+|##..##.|
+|######.|
+|.###...|
+|..#....|
+|.####..|
+|....##.|
+|....##.|
+|....#..|
+|..#.#..|
+|..#.#..|
+|#####..|
+|..###..|
+|...#...|
+|..####.|
++-------+
+"
+        );
     }
 }
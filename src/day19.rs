@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, HashMap};
 
 use strum::{EnumCount, EnumIter, IntoEnumIterator};
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 pub struct Day19 {
     blueprints: Vec<HashMap<Resource, HashMap<Resource, u32>>>,
@@ -10,51 +10,19 @@ pub struct Day19 {
 
 impl Day for Day19 {
     const NAME: &'static str = "Day 19: Not Enough Minerals 🤖 ⛏️ 🪨";
-
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day19.txt")?;
-
-        Ok((day.part1().to_string(), day.part2().to_string()))
-    }
-}
-
-impl Day19 {
-    fn part1(&self) -> u32 {
-        let mut sum = 0;
-
-        for (idx, blueprint) in self.blueprints.iter().enumerate() {
-            let mut cache = HashMap::new();
-
-            let geodes = Self::naive(State::new(24), blueprint, &mut cache);
-
-            println!("[{}] {blueprint:?}: {geodes} geodes", idx + 1);
-
-            sum += (idx as u32 + 1) * geodes;
-        }
-
-        sum
-    }
-
-    fn part2(&self) -> u32 {
-        let mut max = 0;
-
-        for (idx, blueprint) in self.blueprints.iter().take(3).enumerate() {
-            let mut cache = HashMap::new();
-
-            let geodes = Self::naive(State::new(32), blueprint, &mut cache);
-
-            println!("[{}] {blueprint:?}: {geodes} geodes", idx + 1);
-
-            max = std::cmp::max(max, geodes);
-        }
-
-        max
-    }
+    const INPUT: &'static str = "res/day19.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day19_1.txt",
+        part1: Some("33"),
+        // Too slow to run with every test
+        part2: None,
+    }];
 
     fn load(path: &str) -> anyhow::Result<Self> {
         let mut blueprints = vec![];
 
-        for line in std::fs::read_to_string(path)?.lines() {
+        let input = Input::read(path)?;
+        for line in input.lines() {
             let (
                 _,
                 ore_to_ore,
@@ -66,7 +34,7 @@ impl Day19 {
             ) = sscanf::sscanf!(
                 line,
                 "Blueprint {usize}: Each ore robot costs {u32} ore. Each clay robot costs {u32} ore. Each obsidian robot costs {u32} ore and {u32} clay. Each geode robot costs {u32} ore and {u32} obsidian."
-            ).map_err(|e| anyhow::format_err!("{e}"))?;
+            ).map_err(|e| input.error(line, "a blueprint, like in the puzzle").because(e))?;
 
             let blueprint = HashMap::from([
                 (Resource::Ore, HashMap::from([(Resource::Ore, ore_to_ore)])),
@@ -96,6 +64,44 @@ impl Day19 {
         Ok(Self { blueprints })
     }
 
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self).to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self).to_string())
+    }
+}
+
+impl Day19 {
+    pub fn part1(&self) -> u32 {
+        let mut sum = 0;
+
+        for (idx, blueprint) in self.blueprints.iter().enumerate() {
+            let mut cache = HashMap::new();
+
+            let geodes = Self::naive(State::new(24), blueprint, &mut cache);
+
+            sum += (idx as u32 + 1) * geodes;
+        }
+
+        sum
+    }
+
+    pub fn part2(&self) -> u32 {
+        let mut product = 1;
+
+        for blueprint in self.blueprints.iter().take(3) {
+            let mut cache = HashMap::new();
+
+            let geodes = Self::naive(State::new(32), blueprint, &mut cache);
+
+            product *= geodes;
+        }
+
+        product
+    }
+
     fn naive(
         state: State,
         blueprints: &HashMap<Resource, HashMap<Resource, u32>>,
@@ -126,6 +132,17 @@ impl Day19 {
 
         // Try with increasing production
         for resource in Resource::iter() {
+            // Only one bot gets built per minute, so producing more than any bot costs is useless
+            let max_cost = blueprints
+                .values()
+                .filter_map(|costs| costs.get(&resource).cloned())
+                .max()
+                .unwrap_or_default();
+            let production = state.production.get(&resource).cloned().unwrap_or_default();
+            if resource != Resource::Geode && production >= max_cost {
+                continue;
+            }
+
             if let Some(with_new_bot) = state.construct(resource, blueprints) {
                 // Try substate
                 max = std::cmp::max(max, Self::naive(with_new_bot, blueprints, cache));
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use anyhow::Context;
 use strum::IntoEnumIterator;
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 pub struct Day2 {
     rounds: Vec<(Shape, RoundResult)>,
@@ -92,22 +92,7 @@ impl FromStr for RoundResult {
 }
 
 impl Day2 {
-    fn init() -> anyhow::Result<Self> {
-        let mut rounds = vec![];
-
-        for line in std::fs::read_to_string("res/day2.txt")?.split('\n') {
-            let mut split = line.split_whitespace();
-
-            let shape_left = split.next().context("No left sign")?.parse()?;
-            let shape_right = split.next().context("No right sign")?.parse()?;
-
-            rounds.push((shape_left, shape_right));
-        }
-
-        Ok(Self { rounds })
-    }
-
-    fn both(&self) -> anyhow::Result<(u32, u32)> {
+    pub fn both(&self) -> anyhow::Result<(u32, u32)> {
         let mut part1 = 0;
         let mut part2 = 0;
 
@@ -129,12 +114,37 @@ impl Day2 {
 
 impl Day for Day2 {
     const NAME: &'static str = "Day 2: Rock Paper Scissors ✊✋✌️";
+    const INPUT: &'static str = "res/day2.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day2_1.txt",
+        part1: Some("15"),
+        part2: Some("12"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let mut rounds = vec![];
+
+        let input = Input::read(path)?;
+        for line in input.lines() {
+            let mut split = line.split_whitespace();
+            let (Some(left), Some(right), None) = (split.next(), split.next(), split.next()) else {
+                return Err(input.error(line, "two shapes, like 'A Y'").into());
+            };
+
+            let shape_left = input.parse(left, "'A', 'B' or 'C'")?;
+            let shape_right = input.parse(right, "'X', 'Y' or 'Z'")?;
+
+            rounds.push((shape_left, shape_right));
+        }
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::init()?;
+        Ok(Self { rounds })
+    }
 
-        let (part1, part2) = day.both()?;
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(self.both()?.0.to_string())
+    }
 
-        Ok((part1.to_string(), part2.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.both()?.1.to_string())
     }
 }
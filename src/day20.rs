@@ -1,99 +1,88 @@
 use anyhow::Context;
-use std::collections::{HashMap, VecDeque};
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
+
+const DECRYPTION_KEY: i64 = 811589153;
 
 #[derive(Debug)]
 pub struct Day20 {
-    numbers: HashMap<usize, isize>,
-    list: VecDeque<usize>,
-}
-
-impl Day for Day20 {
-    const NAME: &'static str = "Day 20: Grove Positioning System 🌳📍🌳";
-
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day20.txt")?;
-
-        println!("{day:?}");
-
-        Ok(("foo".to_string(), "bar".to_string()))
-    }
+    numbers: Vec<i64>,
 }
 
 impl Day20 {
-    fn part1(&mut self) -> anyhow::Result<isize> {
-        for index in 0..self.numbers.len() {
-            let number = *self
-                .numbers
-                .get(&index)
-                .context("Index should be populated")?;
-
-            let next_num = if number.current_index < 0 {
-                let num_abs = number.inner.abs() as usize;
-
-                if num_abs <= index {
-                    let next_num = index - num_abs;
-                    for i in next_num..index {
-                        *self
-                            .numbers 
-                            .get_mut(&i)
-                            .context("Index should be populated")? += 1;
-                    }
-
-                    next_num
-                } else {
-                    let next_num = self.numbers.len() - 1 - (num_abs - index);
-
-                    for i in next_num..self.numbers.len() {
-                        *self
-                            .numbers
-                            .get_mut(&i)
-                            .context("Index should be populated")? += 1;
-                    }
-
-                    next_num
-                }
-            } else {
-            };
-
-            self.numbers.insert(index, next_num);
-        }
-
-        todo!()
+    pub fn part1(&self) -> anyhow::Result<i64> {
+        Self::grove_coordinates(&self.numbers, 1)
     }
 
-    fn move_left(&mut self, index: usize) {
+    pub fn part2(&self) -> anyhow::Result<i64> {
+        let numbers: Vec<_> = self.numbers.iter().map(|n| n * DECRYPTION_KEY).collect();
 
+        Self::grove_coordinates(&numbers, 10)
+    }
 
+    fn grove_coordinates(numbers: &[i64], rounds: usize) -> anyhow::Result<i64> {
+        let mixed = Self::mix(numbers, rounds);
+        let zero = mixed
+            .iter()
+            .position(|&i| numbers[i] == 0)
+            .context("There's no 0 in the file")?;
+
+        Ok([1000, 2000, 3000]
+            .into_iter()
+            .map(|offset| numbers[mixed[(zero + offset) % mixed.len()]])
+            .sum())
+    }
 
-        if 
+    /// Mixes the numbers, returning the indices of the original numbers in their final order
+    ///
+    /// Numbers are tracked by their original index so duplicates can be told apart. A number
+    /// moving around the circle skips over itself, so it moves modulo `len - 1`.
+    fn mix(numbers: &[i64], rounds: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..numbers.len()).collect();
+        if numbers.len() < 2 {
+            return order;
+        }
 
-        let a = self.numbers.get_mut(&index).unwrap() as *mut Number;
-        let b = self.numbers.get_mut(&(index + 1)).unwrap() as *mut Number;
+        let cycle = numbers.len() as i64 - 1;
+        for _ in 0..rounds {
+            for (i, n) in numbers.iter().enumerate() {
+                let from = order.iter().position(|&j| j == i).unwrap();
+                order.remove(from);
 
-        unsafe {
-            std::ptr::swap(a, b);
+                let to = (from as i64 + n).rem_euclid(cycle) as usize;
+                order.insert(to, i);
+            }
         }
+
+        order
     }
+}
+
+impl Day for Day20 {
+    const NAME: &'static str = "Day 20: Grove Positioning System 🌳📍🌳";
+    const INPUT: &'static str = "res/day20.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day20_1.txt",
+        part1: Some("3"),
+        part2: Some("1623178306"),
+    }];
 
     fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+
         Ok(Self {
-            numbers: std::fs::read_to_string(path)?
+            numbers: input
                 .lines()
-                .enumerate()
-                .map(|(i, l)| {
-                    l.parse().map(|n| {
-                        (
-                            i,
-                            Number {
-                                current_index: i,
-                                inner: n,
-                            },
-                        )
-                    })
-                })
+                .map(|line| input.parse(line, "a number"))
                 .collect::<Result<_, _>>()?,
         })
     }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
+    }
 }
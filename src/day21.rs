@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+
+use crate::{
+    input::{Input, InputError},
+    Day, Example,
+};
+
+/// The monkeys' jobs as an expression DAG, where each monkey is referred to by its index
+#[derive(Debug)]
+pub struct Day21 {
+    jobs: Vec<Job>,
+    root: usize,
+    humn: usize,
+}
+
+impl Day21 {
+    pub fn part1(&self) -> anyhow::Result<i64> {
+        self.eval(self.root, &mut vec![State::Unvisited; self.jobs.len()])
+    }
+
+    /// Finds the number `humn` should yell for both of `root`'s operands to be equal, by walking
+    /// down from `root` and undoing each operation on the way to `humn`
+    pub fn part2(&self) -> anyhow::Result<i64> {
+        let mut states = vec![State::Unvisited; self.jobs.len()];
+        let depends = self.depends_on_humn();
+
+        let Job::Op(_, left, right) = self.jobs[self.root] else {
+            bail!("root should be waiting on two other monkeys");
+        };
+
+        let (mut curr, mut target) = match (depends[left], depends[right]) {
+            (true, false) => (left, self.eval(right, &mut states)?),
+            (false, true) => (right, self.eval(left, &mut states)?),
+            (true, true) => bail!("humn appears on both sides of root"),
+            (false, false) => bail!("root doesn't depend on humn"),
+        };
+
+        while curr != self.humn {
+            let Job::Op(op, left, right) = self.jobs[curr] else {
+                unreachable!("Only operations can depend on humn");
+            };
+
+            (curr, target) = match (depends[left], depends[right]) {
+                (true, false) => (left, op.solve_left(target, self.eval(right, &mut states)?)?),
+                (false, true) => (
+                    right,
+                    op.solve_right(self.eval(left, &mut states)?, target)?,
+                ),
+                _ => bail!("humn appears in both operands of an operation"),
+            };
+        }
+
+        Ok(target)
+    }
+
+    fn eval(&self, monkey: usize, states: &mut [State]) -> anyhow::Result<i64> {
+        match states[monkey] {
+            State::Done(value) => return Ok(value),
+            State::InProgress => bail!("Monkey jobs form a cycle"),
+            State::Unvisited => {}
+        }
+
+        states[monkey] = State::InProgress;
+        let value = match self.jobs[monkey] {
+            Job::Number(n) => n,
+            Job::Op(op, left, right) => {
+                op.apply(self.eval(left, states)?, self.eval(right, states)?)?
+            }
+        };
+        states[monkey] = State::Done(value);
+
+        Ok(value)
+    }
+
+    /// Marks which monkeys need the number `humn` yells to do their job
+    fn depends_on_humn(&self) -> Vec<bool> {
+        fn visit(jobs: &[Job], monkey: usize, humn: usize, memo: &mut [Option<bool>]) -> bool {
+            if let Some(depends) = memo[monkey] {
+                return depends;
+            }
+
+            // Guards against cycles, which `eval` reports
+            memo[monkey] = Some(false);
+            let depends = monkey == humn
+                || match jobs[monkey] {
+                    Job::Number(_) => false,
+                    Job::Op(_, left, right) => {
+                        // Not short-circuiting so both sides are always memoized
+                        visit(jobs, left, humn, memo) | visit(jobs, right, humn, memo)
+                    }
+                };
+            memo[monkey] = Some(depends);
+
+            depends
+        }
+
+        let mut memo = vec![None; self.jobs.len()];
+        (0..self.jobs.len())
+            .map(|monkey| visit(&self.jobs, monkey, self.humn, &mut memo))
+            .collect()
+    }
+}
+
+impl Day for Day21 {
+    const NAME: &'static str = "Day 21: Monkey Math 🐒🧮";
+    const INPUT: &'static str = "res/day21.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day21_1.txt",
+        part1: Some("152"),
+        part2: Some("301"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+
+        let mut ids = HashMap::new();
+        for (i, line) in input.lines().enumerate() {
+            let (name, _) = line
+                .split_once(": ")
+                .ok_or_else(|| input.error(line, "'name: job'"))?;
+
+            if ids.insert(name, i).is_some() {
+                return Err(input.error(name, "a monkey with a single job").into());
+            }
+        }
+
+        let id = |name: &str| {
+            ids.get(name)
+                .copied()
+                .ok_or_else(|| input.error(name, "a known monkey"))
+        };
+        let named = |name: &str| {
+            ids.get(name)
+                .copied()
+                .ok_or_else(|| input.error(input.end(), format!("a monkey named '{name}'")))
+        };
+
+        let jobs = input
+            .lines()
+            .map(|line| {
+                let (_, job) = line.split_once(": ").unwrap();
+                parse_job(&input, job, id)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            jobs,
+            root: named("root")?,
+            humn: named("humn")?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
+    }
+}
+
+#[derive(Debug)]
+enum Job {
+    Number(i64),
+    Op(Op, usize, usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    fn apply(&self, left: i64, right: i64) -> anyhow::Result<i64> {
+        match self {
+            Self::Add => left.checked_add(right),
+            Self::Sub => left.checked_sub(right),
+            Self::Mul => left.checked_mul(right),
+            Self::Div => left.checked_div(right),
+        }
+        .with_context(|| format!("{left} {self} {right} overflows or divides by zero"))
+    }
+
+    /// Finds `left` such that `left op right == result`
+    fn solve_left(&self, result: i64, right: i64) -> anyhow::Result<i64> {
+        match self {
+            Self::Add => Self::Sub.apply(result, right),
+            Self::Sub => Self::Add.apply(result, right),
+            Self::Mul => exact_div(result, right),
+            Self::Div => Self::Mul.apply(result, right),
+        }
+    }
+
+    /// Finds `right` such that `left op right == result`
+    fn solve_right(&self, left: i64, result: i64) -> anyhow::Result<i64> {
+        match self {
+            Self::Add => Self::Sub.apply(result, left),
+            Self::Sub => Self::Sub.apply(left, result),
+            Self::Mul => exact_div(result, left),
+            Self::Div => exact_div(left, result),
+        }
+    }
+}
+
+impl std::str::FromStr for Op {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Sub),
+            "*" => Ok(Self::Mul),
+            "/" => Ok(Self::Div),
+            _ => bail!("Unknown operation '{s}'"),
+        }
+    }
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+        }
+    }
+}
+
+/// Divides, failing if the monkeys' integer division would have to round for the result to work
+fn exact_div(dividend: i64, divisor: i64) -> anyhow::Result<i64> {
+    if divisor == 0 || dividend % divisor != 0 {
+        bail!("No integer solves x * {divisor} == {dividend}");
+    }
+
+    Ok(dividend / divisor)
+}
+
+/// Parses `job`, a slice of `input`
+fn parse_job(
+    input: &Input,
+    job: &str,
+    id: impl Fn(&str) -> Result<usize, InputError>,
+) -> Result<Job, InputError> {
+    Ok(match job.split_ascii_whitespace().collect::<Vec<_>>()[..] {
+        [n] => Job::Number(input.parse(n, "a number")?),
+        [left, op, right] => Job::Op(
+            input.parse(op, "'+', '-', '*' or '/'")?,
+            id(left)?,
+            id(right)?,
+        ),
+        _ => return Err(input.error(job, "a number or an operation, like 'aaaa + bbbb'")),
+    })
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Unvisited,
+    InProgress,
+    Done(i64),
+}
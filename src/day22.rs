@@ -0,0 +1,411 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{bail, Context};
+
+use crate::{
+    dir::Dir,
+    grid::Grid,
+    input::{char_at, Input},
+    Day, Example,
+};
+
+type Pos = (usize, usize);
+
+pub struct Day22 {
+    /// Every row is padded with [`Tile::Void`] to the width of the widest one
+    map: Grid<Tile>,
+    path: Vec<Move>,
+}
+
+impl Day22 {
+    pub fn part1(&self) -> anyhow::Result<usize> {
+        self.walk(|pos, dir| self.wrap_flat(pos, dir))
+    }
+
+    pub fn part2(&self) -> anyhow::Result<usize> {
+        let cube = Cube::fold(&self.map)?;
+
+        self.walk(|pos, dir| cube.wrap(pos, dir))
+    }
+
+    /// Follows the path, calling `wrap` to find where stepping off the map from a tile leads to,
+    /// and returns the final password
+    fn walk(&self, wrap: impl Fn(Pos, Dir) -> (Pos, Dir)) -> anyhow::Result<usize> {
+        let start = self
+            .map
+            .row(0)
+            .iter()
+            .position(|tile| *tile == Tile::Open)
+            .context("No open tile on the top row")?;
+
+        let mut pos = (0, start);
+        let mut dir = Dir::East;
+
+        for mov in &self.path {
+            match mov {
+                Move::Left => dir = dir.turn_left(),
+                Move::Right => dir = dir.turn_right(),
+                Move::Forward(n) => {
+                    for _ in 0..*n {
+                        let (next_pos, next_dir) = self
+                            .step(pos, dir)
+                            .map(|next| (next, dir))
+                            .unwrap_or_else(|| wrap(pos, dir));
+
+                        if self.map[next_pos] == Tile::Wall {
+                            break;
+                        }
+
+                        (pos, dir) = (next_pos, next_dir);
+                    }
+                }
+            }
+        }
+
+        let facing = match dir {
+            Dir::East => 0,
+            Dir::South => 1,
+            Dir::West => 2,
+            Dir::North => 3,
+        };
+
+        Ok(1000 * (pos.0 + 1) + 4 * (pos.1 + 1) + facing)
+    }
+
+    /// The tile in front of `pos`, if it's on the map
+    fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        self.map
+            .offset(pos, dir.forward())
+            .filter(|&next| self.map[next] != Tile::Void)
+    }
+
+    /// Wraps around to the opposite edge of the row or column
+    fn wrap_flat(&self, mut pos: Pos, dir: Dir) -> (Pos, Dir) {
+        while let Some(prev) = self.step(pos, dir.opposite()) {
+            pos = prev;
+        }
+
+        (pos, dir)
+    }
+}
+
+impl Day for Day22 {
+    const NAME: &'static str = "Day 22: Monkey Map 🗺️🎲";
+    const INPUT: &'static str = "res/day22.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day22_1.txt",
+        part1: Some("6032"),
+        part2: Some("5031"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+        let (map, path) = input
+            .text()
+            .split_once("\n\n")
+            .ok_or_else(|| input.error(input.end(), "an empty line, then the path"))?;
+
+        let width = map
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .ok_or_else(|| input.error(input.text(), "a map"))?;
+
+        let mut grid = Grid::new(map.lines().count(), width, Tile::Void);
+        for (i, line) in map.lines().enumerate() {
+            for (j, c) in line.char_indices() {
+                grid[(i, j)] = Tile::try_from(c)
+                    .map_err(|_| input.error(char_at(line, j), "' ', '.' or '#'"))?;
+            }
+        }
+
+        let mut moves = vec![];
+        let mut n = None;
+        let path = path.trim();
+        for (j, c) in path.char_indices() {
+            if let Some(digit) = c.to_digit(10) {
+                n = Some(n.unwrap_or(0) * 10 + digit as usize);
+                continue;
+            }
+
+            moves.extend(n.take().map(Move::Forward));
+            moves.push(match c {
+                'L' => Move::Left,
+                'R' => Move::Right,
+                _ => {
+                    let at = char_at(path, j);
+                    return Err(input.error(at, "a number of steps, 'L' or 'R'").into());
+                }
+            });
+        }
+        moves.extend(n.map(Move::Forward));
+
+        Ok(Self {
+            map: grid,
+            path: moves,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            ' ' => Ok(Self::Void),
+            '.' => Ok(Self::Open),
+            '#' => Ok(Self::Wall),
+            _ => bail!("Unexpected map tile '{c}'"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Move {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+type V3 = [i64; 3];
+
+fn neg(v: V3) -> V3 {
+    v.map(|x| -x)
+}
+
+fn dot(a: V3, b: V3) -> i64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Where a face of the net ends up once folded, described by the outward normal of the face and
+/// the directions its columns (`right`) and rows (`down`) grow towards
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    origin: Pos,
+    normal: V3,
+    right: V3,
+    down: V3,
+}
+
+impl Face {
+    /// The direction moving towards `dir` on the net points to in 3D
+    fn axis(&self, dir: Dir) -> V3 {
+        match dir {
+            Dir::North => neg(self.down),
+            Dir::East => self.right,
+            Dir::South => self.down,
+            Dir::West => neg(self.right),
+        }
+    }
+
+    /// The face next to this one towards `dir` on the net, folded by 90° along their common edge
+    fn roll(&self, dir: Dir, origin: Pos) -> Self {
+        let mut next = Self {
+            origin,
+            normal: self.axis(dir),
+            ..*self
+        };
+
+        match dir {
+            Dir::North => next.down = self.normal,
+            Dir::East => next.right = neg(self.normal),
+            Dir::South => next.down = neg(self.normal),
+            Dir::West => next.right = self.normal,
+        }
+
+        next
+    }
+}
+
+/// A net folded onto a cube
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+    /// Face indices by their position in the net, counted in faces
+    net: HashMap<Pos, usize>,
+}
+
+impl Cube {
+    fn fold(map: &Grid<Tile>) -> anyhow::Result<Self> {
+        let n_tiles = map.iter().filter(|(_, t)| **t != Tile::Void).count();
+        let size = (1..)
+            .take_while(|size| 6 * size * size <= n_tiles)
+            .last()
+            .filter(|size| 6 * size * size == n_tiles)
+            .context("The map can't be folded into a cube, its area isn't 6 squares")?;
+
+        let mut cells = vec![];
+        for i in (0..map.height()).step_by(size) {
+            for j in (0..map.width()).step_by(size) {
+                let is_void = |pos| map.get(pos).is_none_or(|tile| *tile == Tile::Void);
+                let voids = (i..i + size)
+                    .flat_map(|i| (j..j + size).map(move |j| (i, j)))
+                    .filter(|&pos| is_void(pos))
+                    .count();
+
+                match voids {
+                    0 => cells.push((i / size, j / size)),
+                    v if v == size * size => {}
+                    _ => bail!("The map can't be folded into a cube, it doesn't split into faces"),
+                }
+            }
+        }
+
+        let mut faces = vec![Face {
+            origin: (cells[0].0 * size, cells[0].1 * size),
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut net = HashMap::from([(cells[0], 0)]);
+        let mut to_visit = VecDeque::from([cells[0]]);
+
+        while let Some(cell) = to_visit.pop_front() {
+            let face = faces[net[&cell]];
+
+            for dir in Dir::ALL {
+                let (di, dj) = dir.forward();
+                let Some(next) = cell
+                    .0
+                    .checked_add_signed(di)
+                    .zip(cell.1.checked_add_signed(dj))
+                else {
+                    continue;
+                };
+
+                if cells.contains(&next) && !net.contains_key(&next) {
+                    net.insert(next, faces.len());
+                    faces.push(face.roll(dir, (next.0 * size, next.1 * size)));
+                    to_visit.push_back(next);
+                }
+            }
+        }
+
+        for (i, face) in faces.iter().enumerate() {
+            if faces[..i].iter().any(|other| other.normal == face.normal) {
+                bail!("The map can't be folded into a cube, two faces overlap");
+            }
+        }
+
+        if faces.len() != 6 {
+            bail!("The map can't be folded into a cube, its faces aren't connected");
+        }
+
+        Ok(Self { size, faces, net })
+    }
+
+    /// Where stepping off the edge of a face from `pos` leads to, along with the new direction
+    fn wrap(&self, pos: Pos, dir: Dir) -> (Pos, Dir) {
+        let from = self.faces[self.net[&(pos.0 / self.size, pos.1 / self.size)]];
+        let heading = from.axis(dir);
+        let to = self
+            .faces
+            .iter()
+            .find(|face| face.normal == heading)
+            .expect("Each direction has a face");
+
+        // Tile centres in 3D, scaled by 2 so they stay integers, with the cube's centre at 0
+        let size = self.size as i64;
+        let centre = |x: usize| 2 * x as i64 - (size - 1);
+        let (i, j) = (pos.0 - from.origin.0, pos.1 - from.origin.1);
+        let p: V3 = std::array::from_fn(|k| {
+            centre(j) * from.right[k] + centre(i) * from.down[k] + size * from.normal[k]
+        });
+
+        // Over the edge, one tile further along `heading` and half a tile down the new face
+        let q: V3 = std::array::from_fn(|k| p[k] + heading[k] - from.normal[k]);
+        let local = |axis: V3| ((dot(q, axis) + size - 1) / 2) as usize;
+
+        let down = neg(from.normal);
+        let dir = Dir::ALL
+            .into_iter()
+            .find(|dir| to.axis(*dir) == down)
+            .expect("The new face is perpendicular to the old one");
+
+        (
+            (to.origin.0 + local(to.down), to.origin.1 + local(to.right)),
+            dir,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Walking straight on for 4 edges' worth of tiles should lead back to the same tile, facing
+    /// the same way, on each of the 11 nets of a cube
+    #[test]
+    fn all_nets() {
+        let nets = [
+            "#...\n####\n#...",
+            "#...\n####\n.#..",
+            "#...\n####\n..#.",
+            "#...\n####\n...#",
+            ".#..\n####\n.#..",
+            ".#..\n####\n..#.",
+            "##..\n.###\n.#..",
+            "##..\n.###\n..#.",
+            "##..\n.###\n...#",
+            "##..\n.##.\n..##",
+            "###..\n..###",
+        ];
+        let size = 3;
+
+        for net in nets {
+            let width = net.lines().next().unwrap().len() * size;
+            let mut map = Grid::new(0, width, Tile::Void);
+            for line in net.lines() {
+                let row: Vec<_> = line
+                    .chars()
+                    .flat_map(|c| {
+                        let tile = if c == '#' { Tile::Open } else { Tile::Void };
+                        [tile; 3]
+                    })
+                    .collect();
+                for _ in 0..size {
+                    map.push_row(row.iter().copied());
+                }
+            }
+
+            let day = Day22 {
+                map,
+                path: vec![Move::Forward(4 * size)],
+            };
+            let cube = Cube::fold(&day.map).unwrap();
+
+            for (start, tile) in day.map.iter() {
+                if *tile == Tile::Void {
+                    continue;
+                }
+
+                for start_dir in Dir::ALL {
+                    let (mut pos, mut dir) = (start, start_dir);
+                    for _ in 0..4 * size {
+                        (pos, dir) = day
+                            .step(pos, dir)
+                            .map(|next| (next, dir))
+                            .unwrap_or_else(|| cube.wrap(pos, dir));
+                    }
+
+                    assert_eq!((pos, dir), (start, start_dir), "{net}");
+                }
+            }
+        }
+    }
+}
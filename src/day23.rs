@@ -0,0 +1,191 @@
+use crate::{
+    dir::Dir,
+    grid::Grid,
+    input::{char_at, Input},
+    Day, Example,
+};
+
+/// Order in which elves consider moving on the first round, rotating by one every round
+const ORDER: [Dir; 4] = [Dir::North, Dir::South, Dir::West, Dir::East];
+
+pub struct Day23 {
+    elves: Vec<(usize, usize)>,
+}
+
+impl Day23 {
+    pub fn part1(&self) -> usize {
+        let mut grove = Grove::new(&self.elves);
+        for _ in 0..10 {
+            grove.round();
+        }
+
+        grove.empty_ground()
+    }
+
+    pub fn part2(&self) -> usize {
+        let mut grove = Grove::new(&self.elves);
+
+        (1..).find(|_| !grove.round()).unwrap()
+    }
+}
+
+impl Day for Day23 {
+    const NAME: &'static str = "Day 23: Unstable Diffusion 🌱🧝";
+    const INPUT: &'static str = "res/day23.txt";
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "res/examples/day23_1.txt",
+            part1: Some("25"),
+            part2: Some("4"),
+        },
+        Example {
+            input: "res/examples/day23_2.txt",
+            part1: Some("110"),
+            part2: Some("20"),
+        },
+    ];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let mut elves = vec![];
+
+        let input = Input::read(path)?;
+        for (i, line) in input.lines().enumerate() {
+            for (j, (at, c)) in line.char_indices().enumerate() {
+                match c {
+                    '#' => elves.push((i, j)),
+                    '.' => {}
+                    _ => return Err(input.error(char_at(line, at), "'#' or '.'").into()),
+                }
+            }
+        }
+
+        if elves.is_empty() {
+            return Err(input.error(input.text(), "at least one elf '#'").into());
+        }
+
+        Ok(Self { elves })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self).to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self).to_string())
+    }
+}
+
+/// The elves on a dense grid, which is regrown whenever they get close to its edges
+struct Grove {
+    elves: Vec<(usize, usize)>,
+    occupied: Grid<bool>,
+    /// Number of elves proposing each tile, only non-zero during a round
+    proposals: Grid<u8>,
+    round: usize,
+}
+
+impl Grove {
+    fn new(elves: &[(usize, usize)]) -> Self {
+        let mut grove = Self {
+            elves: elves.to_vec(),
+            occupied: Grid::new(0, 0, false),
+            proposals: Grid::new(0, 0, 0),
+            round: 0,
+        };
+        grove.regrow();
+
+        grove
+    }
+
+    /// Bounding box of the elves, as the min and max (i, j)
+    fn bounds(&self) -> ((usize, usize), (usize, usize)) {
+        self.elves.iter().fold(
+            ((usize::MAX, usize::MAX), (0, 0)),
+            |((min_i, min_j), (max_i, max_j)), &(i, j)| {
+                ((min_i.min(i), min_j.min(j)), (max_i.max(i), max_j.max(j)))
+            },
+        )
+    }
+
+    /// Recentres the elves on a grid with as much room around them as they take up
+    fn regrow(&mut self) {
+        let ((min_i, min_j), (max_i, max_j)) = self.bounds();
+        let margin = (max_i - min_i).max(max_j - min_j) + 2;
+
+        let height = max_i - min_i + 1 + 2 * margin;
+        let width = max_j - min_j + 1 + 2 * margin;
+
+        for elf in &mut self.elves {
+            *elf = (elf.0 - min_i + margin, elf.1 - min_j + margin);
+        }
+
+        self.occupied = Grid::new(height, width, false);
+        for &elf in &self.elves {
+            self.occupied[elf] = true;
+        }
+        self.proposals = Grid::new(height, width, 0);
+    }
+
+    fn proposal(&self, (i, j): (usize, usize)) -> Option<(usize, usize)> {
+        let free = |(di, dj): (isize, isize)| {
+            !self.occupied[(i.wrapping_add_signed(di), j.wrapping_add_signed(dj))]
+        };
+
+        if Dir::AROUND.into_iter().all(free) {
+            return None;
+        }
+
+        (0..4)
+            .map(|k| ORDER[(self.round + k) % 4])
+            .find(|dir| dir.ahead().into_iter().all(free))
+            .map(|dir| {
+                let (di, dj) = dir.forward();
+                (i.wrapping_add_signed(di), j.wrapping_add_signed(dj))
+            })
+    }
+
+    /// Plays a round, returning whether any elf moved
+    fn round(&mut self) -> bool {
+        let ((min_i, min_j), (max_i, max_j)) = self.bounds();
+        // Elves on the edge would look past it
+        if min_i == 0
+            || min_j == 0
+            || max_i + 1 == self.occupied.height()
+            || max_j + 1 == self.occupied.width()
+        {
+            self.regrow();
+        }
+
+        let proposals: Vec<_> = self.elves.iter().map(|&elf| self.proposal(elf)).collect();
+        for &proposal in proposals.iter().flatten() {
+            self.proposals[proposal] += 1;
+        }
+
+        let mut moved = false;
+        for (elf, proposal) in self.elves.iter_mut().zip(&proposals) {
+            let Some(proposal) = *proposal else {
+                continue;
+            };
+
+            if self.proposals[proposal] == 1 {
+                self.occupied[*elf] = false;
+                self.occupied[proposal] = true;
+                *elf = proposal;
+                moved = true;
+            }
+        }
+
+        for &proposal in proposals.iter().flatten() {
+            self.proposals[proposal] = 0;
+        }
+        self.round += 1;
+
+        moved
+    }
+
+    fn empty_ground(&self) -> usize {
+        let ((min_i, min_j), (max_i, max_j)) = self.bounds();
+
+        (max_i - min_i + 1) * (max_j - min_j + 1) - self.elves.len()
+    }
+}
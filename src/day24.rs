@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use anyhow::Context;
+
+use crate::{
+    dir::Dir,
+    grid::Grid,
+    input::{char_at, Input},
+    pf, Day, Example,
+};
+
+type Pos = (usize, usize);
+
+/// The valley, with `(i, j)` counted from the top left wall
+pub struct Day24 {
+    /// Initial blizzards on the inner tiles (without walls), by the direction they blow towards
+    blizzards: [Grid<bool>; 4],
+    entrance: Pos,
+    exit: Pos,
+}
+
+impl Day24 {
+    pub fn part1(&self) -> anyhow::Result<usize> {
+        self.cross(self.entrance, self.exit, 0)
+    }
+
+    pub fn part2(&self) -> anyhow::Result<usize> {
+        let there = self.cross(self.entrance, self.exit, 0)?;
+        let back = self.cross(self.exit, self.entrance, there)?;
+        let there_again = self.cross(self.entrance, self.exit, there + back)?;
+
+        Ok(there + back + there_again)
+    }
+
+    /// The blizzards repeat with this period, so a state only needs the time modulo it
+    fn period(&self) -> usize {
+        let (h, w) = self.size();
+        h * w / gcd(h, w)
+    }
+
+    /// Height and width of the valley, without its walls
+    fn size(&self) -> (usize, usize) {
+        (self.blizzards[0].height(), self.blizzards[0].width())
+    }
+
+    /// Finds how many minutes it takes to go from `from` to `to`, setting off on minute `start`
+    fn cross(&self, from: Pos, to: Pos, start: usize) -> anyhow::Result<usize> {
+        let period = self.period();
+
+        let (_, minutes) = pf::a_star(
+            HashSet::from([(from, start % period)]),
+            |&(pos, _)| pos == to,
+            |&((i, j), t)| {
+                let t = (t + 1) % period;
+
+                Dir::ALL
+                    .iter()
+                    .filter_map(|dir| {
+                        let (di, dj) = dir.forward();
+                        Some((i.checked_add_signed(di)?, j.checked_add_signed(dj)?))
+                    })
+                    .chain([(i, j)])
+                    .filter(|&pos| self.is_clear(pos, t))
+                    .map(|pos| ((pos, t), 1))
+                    .collect()
+            },
+            |&((i, j), _)| i.abs_diff(to.0) + j.abs_diff(to.1),
+        )
+        .with_context(|| format!("No way from {from:?} to {to:?}"))?;
+
+        Ok(minutes)
+    }
+
+    /// Whether `pos` is inside the valley and free of blizzards on minute `t`
+    fn is_clear(&self, pos: Pos, t: usize) -> bool {
+        if pos == self.entrance || pos == self.exit {
+            return true;
+        }
+
+        let (i, j) = pos;
+        let (h, w) = self.size();
+        if !(1..=h).contains(&i) || !(1..=w).contains(&j) {
+            return false;
+        }
+
+        // Where each kind of blizzard would have to start from to be on `pos` by now
+        let (i, j) = (i - 1, j - 1);
+        let [north, east, south, west] = &self.blizzards;
+
+        !(north[((i + t) % h, j)]
+            || east[(i, (j + w - t % w) % w)]
+            || south[((i + h - t % h) % h, j)]
+            || west[(i, (j + t) % w)])
+    }
+}
+
+impl Day for Day24 {
+    const NAME: &'static str = "Day 24: Blizzard Basin 🌨️🌬️";
+    const INPUT: &'static str = "res/day24.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day24_1.txt",
+        part1: Some("18"),
+        part2: Some("54"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+        let lines: Vec<&str> = input.lines().collect();
+
+        if lines.len() < 3 {
+            return Err(input
+                .error(input.text(), "at least one row between the valley's walls")
+                .into());
+        }
+
+        let height = lines.len() - 2;
+        let width = lines[0]
+            .len()
+            .checked_sub(2)
+            .filter(|width| *width > 0)
+            .ok_or_else(|| input.error(lines[0], "at least one column between the walls"))?;
+
+        let gap = |line: &str| {
+            let gaps: Vec<_> = line.match_indices('.').map(|(j, _)| j).collect();
+            match gaps[..] {
+                [j] => Ok(j),
+                _ => Err(input.error(line, "a wall with a single gap")),
+            }
+        };
+        let entrance = (0, gap(lines[0])?);
+        let exit = (height + 1, gap(lines[height + 1])?);
+
+        let mut blizzards: [_; 4] = std::array::from_fn(|_| Grid::new(height, width, false));
+        for (i, line) in lines[1..=height].iter().enumerate() {
+            let inner = line
+                .strip_prefix('#')
+                .and_then(|line| line.strip_suffix('#'))
+                .filter(|inner| inner.len() == width)
+                .ok_or_else(|| input.error(line, format!("{width} tiles between walls")))?;
+
+            for (j, c) in inner.char_indices() {
+                let dir = match c {
+                    '^' => Dir::North,
+                    '>' => Dir::East,
+                    'v' => Dir::South,
+                    '<' => Dir::West,
+                    '.' => continue,
+                    _ => {
+                        let at = char_at(inner, j);
+                        return Err(input.error(at, "'^', '>', 'v', '<' or '.'").into());
+                    }
+                };
+
+                blizzards[dir as usize][(i, j)] = true;
+            }
+        }
+
+        Ok(Self {
+            blizzards,
+            entrance,
+            exit,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
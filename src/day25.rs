@@ -0,0 +1,67 @@
+use crate::{input::Input, snafu::Snafu, Day, Example};
+
+pub struct Day25 {
+    numbers: Vec<Snafu>,
+}
+
+impl Day25 {
+    /// Adds the numbers up in SNAFU directly
+    pub fn part1(&self) -> Snafu {
+        self.numbers.iter().sum()
+    }
+
+    /// Adds the numbers up in decimal, converting them back and forth
+    pub fn part1_decimal(&self) -> anyhow::Result<Snafu> {
+        let mut sum = 0_i128;
+        for n in &self.numbers {
+            sum = sum
+                .checked_add(i128::try_from(n)?)
+                .ok_or_else(|| anyhow::format_err!("The sum doesn't fit in i128"))?;
+        }
+
+        Ok(Snafu::from(sum))
+    }
+}
+
+impl Day for Day25 {
+    const NAME: &'static str = "Day 25: Full of Hot Air 🎈🔥";
+    const INPUT: &'static str = "res/day25.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day25_1.txt",
+        part1: Some("2=-1=0"),
+        part2: None,
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+
+        Ok(Self {
+            numbers: input
+                .lines()
+                .map(|line| input.parse(line, "a SNAFU number"))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self).to_string())
+    }
+
+    /// There's no puzzle left, just the blender to start
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok("Merry Christmas! 🎄".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day25;
+    use crate::Day;
+
+    #[test]
+    fn part1_cross_check() {
+        let day = Day25::load("res/examples/day25_1.txt").unwrap();
+
+        assert_eq!(day.part1(), day.part1_decimal().unwrap());
+    }
+}
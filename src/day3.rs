@@ -2,23 +2,17 @@ use std::collections::HashSet;
 
 use anyhow::{bail, Context};
 
-use crate::Day;
+use crate::{
+    input::{char_at, Input},
+    Day, Example,
+};
 
 pub struct Day3 {
     rucksacks: Vec<Vec<char>>,
 }
 
 impl Day3 {
-    fn init() -> anyhow::Result<Self> {
-        Ok(Self {
-            rucksacks: std::fs::read_to_string("res/day3.txt")?
-                .split_whitespace()
-                .map(|line| line.to_string().chars().collect())
-                .collect(),
-        })
-    }
-
-    fn part1(&self) -> anyhow::Result<u32> {
+    pub fn part1(&self) -> anyhow::Result<u32> {
         let mut priorities = 0;
 
         for rucksack in self.rucksacks.iter() {
@@ -39,7 +33,7 @@ impl Day3 {
         Ok(priorities)
     }
 
-    fn part2(&self) -> anyhow::Result<u32> {
+    pub fn part2(&self) -> anyhow::Result<u32> {
         let mut priorities = 0;
 
         for group in self.rucksacks.chunks(3) {
@@ -62,11 +56,34 @@ impl Day3 {
 
 impl Day for Day3 {
     const NAME: &'static str = "Day 3: Rucksack Reorganization 🎒";
+    const INPUT: &'static str = "res/day3.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day3_1.txt",
+        part1: Some("157"),
+        part2: Some("70"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+        let mut rucksacks = vec![];
+
+        for line in input.lines() {
+            if let Some(j) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(input.error(char_at(line, j), "an item letter").into());
+            }
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Day3::init()?;
+            rucksacks.push(line.chars().collect());
+        }
+
+        Ok(Self { rucksacks })
+    }
 
-        Ok((day.part1()?.to_string(), day.part2()?.to_string()))
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
     }
 }
 
@@ -94,23 +111,4 @@ mod tests {
         assert!(char_to_prio('5').is_err());
         assert!(char_to_prio('9').is_err());
     }
-
-    #[test]
-    fn example() {
-        let day = Day3 {
-            rucksacks: [
-                "vJrwpWtwJgWrhcsFMMfFFhFp",
-                "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
-                "PmmdzqPrVvPwwTWBwg",
-                "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
-                "ttgJtRGJQctTZtZT",
-                "CrZsJsPPZsGzwwsLwLmpwMDw",
-            ]
-            .into_iter()
-            .map(|r| r.to_string().chars().collect())
-            .collect(),
-        };
-
-        assert_eq!(day.part1().unwrap(), 157);
-    }
 }
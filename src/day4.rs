@@ -1,28 +1,11 @@
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 pub struct Day4 {
     sections: Vec<(Section, Section)>,
 }
 
 impl Day4 {
-    fn load(file: &str) -> anyhow::Result<Self> {
-        let mut sections = vec![];
-
-        for line in std::fs::read_to_string(file)?.split_whitespace() {
-            let (a, b, c, d) = sscanf::scanf!(line, "{}-{},{}-{}", u32, u32, u32, u32)
-                .map_err(|err| anyhow::Error::msg(err.to_string()))?;
-
-            let left = Section { start: a, end: b };
-
-            let right = Section { start: c, end: d };
-
-            sections.push((left, right));
-        }
-
-        Ok(Self { sections })
-    }
-
-    fn both(&self) -> anyhow::Result<(u32, u32)> {
+    pub fn both(&self) -> anyhow::Result<(u32, u32)> {
         let mut n_contained = 0;
         let mut n_overlapping = 0;
 
@@ -42,13 +25,37 @@ impl Day4 {
 
 impl Day for Day4 {
     const NAME: &'static str = "Day 4: Camp Cleanup ⛺️ 🧹";
+    const INPUT: &'static str = "res/day4.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day4_1.txt",
+        part1: Some("2"),
+        part2: Some("4"),
+    }];
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Day4::load("res/day4.txt")?;
+    fn load(file: &str) -> anyhow::Result<Self> {
+        let mut sections = vec![];
+
+        let input = Input::read(file)?;
+        for line in input.lines() {
+            let (a, b, c, d) = sscanf::scanf!(line, "{}-{},{}-{}", u32, u32, u32, u32)
+                .map_err(|err| input.error(line, "two ranges, like '2-4,6-8'").because(err))?;
+
+            let left = Section { start: a, end: b };
+
+            let right = Section { start: c, end: d };
 
-        let (part1, part2) = day.both()?;
+            sections.push((left, right));
+        }
+
+        Ok(Self { sections })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(self.both()?.0.to_string())
+    }
 
-        Ok((part1.to_string(), part2.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.both()?.1.to_string())
     }
 }
 
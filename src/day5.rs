@@ -1,8 +1,8 @@
 use std::collections::VecDeque;
 
-use anyhow::{bail, Context};
+use anyhow::Context;
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 #[derive(Clone, Debug)]
 pub struct Day5 {
@@ -11,45 +11,7 @@ pub struct Day5 {
 }
 
 impl Day5 {
-    #[allow(clippy::while_let_on_iterator)]
-    fn load(file: &str) -> anyhow::Result<Self> {
-        let content = std::fs::read_to_string(file)?;
-        let mut lines = content.split('\n');
-
-        let mut stacks = vec![];
-        let mut stack = VecDeque::default();
-        while let Some(line) = lines.next() {
-            if line.is_empty() {
-                break;
-            }
-
-            for crt in line.split_ascii_whitespace() {
-                if crt.len() != 1 {
-                    bail!("Crates should have 1-char-long name");
-                };
-                let crt = crt.chars().next().unwrap();
-                stack.push_front(crt);
-            }
-
-            stacks.push(std::mem::take(&mut stack));
-        }
-
-        let mut moves = vec![];
-        while let Some(line) = lines.next() {
-            let (n, from, to) = sscanf::scanf!(line, "move {} from {} to {}", usize, usize, usize)
-                .map_err(|err| anyhow::Error::msg(err.to_string()))?;
-
-            moves.push(Move {
-                n,
-                from: from - 1,
-                to: to - 1,
-            });
-        }
-
-        Ok(Self { stacks, moves })
-    }
-
-    fn part1(&mut self) -> anyhow::Result<String> {
+    pub fn part1(&mut self) -> anyhow::Result<String> {
         for (i, mov) in self.moves.iter().enumerate() {
             for _ in 0..mov.n {
                 let crt = self.stacks[mov.from]
@@ -67,7 +29,7 @@ impl Day5 {
             .collect())
     }
 
-    fn part2(&mut self) -> anyhow::Result<String> {
+    pub fn part2(&mut self) -> anyhow::Result<String> {
         for mov in self.moves.iter() {
             let stack_size = self.stacks[mov.from].len();
             let mut crts = self.stacks[mov.from].split_off(stack_size - mov.n);
@@ -84,11 +46,61 @@ impl Day5 {
 
 impl Day for Day5 {
     const NAME: &'static str = "Day 5: Supply Stacks 🏗️ 📦";
+    const INPUT: &'static str = "res/day5.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day5_1.txt",
+        part1: Some("CMZ"),
+        part2: Some("MCD"),
+    }];
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let mut day = Self::load("res/day5.txt")?;
+    #[allow(clippy::while_let_on_iterator)]
+    fn load(file: &str) -> anyhow::Result<Self> {
+        let input = Input::read(file)?;
+        let mut lines = input.text().split('\n');
+
+        let mut stacks = vec![];
+        let mut stack = VecDeque::default();
+        while let Some(line) = lines.next() {
+            if line.is_empty() {
+                break;
+            }
+
+            for crt in line.split_ascii_whitespace() {
+                if crt.chars().count() != 1 {
+                    return Err(input.error(crt, "a single-character crate").into());
+                };
+                let crt = crt.chars().next().unwrap();
+                stack.push_front(crt);
+            }
+
+            stacks.push(std::mem::take(&mut stack));
+        }
+
+        let mut moves = vec![];
+        while let Some(line) = lines.next() {
+            let (n, from, to) = sscanf::scanf!(line, "move {} from {} to {}", usize, usize, usize)
+                .map_err(|err| {
+                    input
+                        .error(line, "a move, like 'move 1 from 2 to 1'")
+                        .because(err)
+                })?;
+
+            moves.push(Move {
+                n,
+                from: from - 1,
+                to: to - 1,
+            });
+        }
+
+        Ok(Self { stacks, moves })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Self::part1(&mut self.clone())
+    }
 
-        Ok((day.clone().part1()?, day.part2()?))
+    fn part2(&self) -> anyhow::Result<String> {
+        Self::part2(&mut self.clone())
     }
 }
 
@@ -1,19 +1,13 @@
 use std::collections::HashSet;
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 pub struct Day6 {
     data: Vec<char>,
 }
 
 impl Day6 {
-    fn load() -> anyhow::Result<Self> {
-        let data = std::fs::read_to_string("res/day6.txt")?.chars().collect();
-
-        Ok(Self { data })
-    }
-
-    fn part1(&self) -> anyhow::Result<usize> {
+    pub fn part1(&self) -> anyhow::Result<usize> {
         for (idx, win) in self.data.windows(4).enumerate() {
             if win.iter().collect::<HashSet<_>>().len() == 4 {
                 return Ok(idx + 4);
@@ -23,7 +17,7 @@ impl Day6 {
         anyhow::bail!("Couldn't find solution");
     }
 
-    fn part2(&self) -> anyhow::Result<usize> {
+    pub fn part2(&self) -> anyhow::Result<usize> {
         for (idx, win) in self.data.windows(14).enumerate() {
             if win.iter().collect::<HashSet<_>>().len() == 14 {
                 return Ok(idx + 14);
@@ -36,10 +30,46 @@ impl Day6 {
 
 impl Day for Day6 {
     const NAME: &'static str = "Day 6: Tuning Trouble 📻 🪛";
+    const INPUT: &'static str = "res/day6.txt";
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "res/examples/day6_1.txt",
+            part1: Some("7"),
+            part2: Some("19"),
+        },
+        Example {
+            input: "res/examples/day6_2.txt",
+            part1: Some("5"),
+            part2: Some("23"),
+        },
+        Example {
+            input: "res/examples/day6_3.txt",
+            part1: Some("6"),
+            part2: Some("23"),
+        },
+        Example {
+            input: "res/examples/day6_4.txt",
+            part1: Some("10"),
+            part2: Some("29"),
+        },
+        Example {
+            input: "res/examples/day6_5.txt",
+            part1: Some("11"),
+            part2: Some("26"),
+        },
+    ];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let data = Input::read(path)?.text().chars().collect();
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load()?;
+        Ok(Self { data })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self)?.to_string())
+    }
 
-        Ok((day.part1()?.to_string(), day.part2()?.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self)?.to_string())
     }
 }
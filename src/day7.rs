@@ -1,19 +1,67 @@
 use std::collections::{HashMap, HashSet};
 
-use anyhow::{bail, Context};
+use anyhow::Context;
 
-use crate::Day;
+use crate::{input::Input, Day, Example};
 
 pub struct Day7 {
     directories: HashMap<String, Directory>,
 }
 
 impl Day7 {
-    pub fn load(path: &str) -> anyhow::Result<Self> {
-        let lines = std::fs::read_to_string(path)?
-            .lines()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>();
+    pub fn directory_size(&self, directory: &str) -> u32 {
+        let Some(directory) = self.directories.get(directory) else {
+            return 0;
+        };
+
+        let mut size = directory.files.values().sum();
+
+        for subdir in directory.subdirectories.iter() {
+            size += self.directory_size(subdir);
+        }
+
+        size
+    }
+
+    pub fn both(&self) -> anyhow::Result<(u32, u32)> {
+        let min_delete = 30000000 - (70000000 - self.directory_size("//"));
+
+        let mut part1 = 0;
+        let mut part2 = None;
+
+        for dir in self.directories.keys() {
+            if dir != "//" {
+                let size = self.directory_size(dir);
+
+                if size <= 100_000 {
+                    part1 += size;
+                }
+
+                if size >= min_delete && part2.map(|prev| prev > size).unwrap_or(true) {
+                    part2 = Some(size);
+                }
+            }
+        }
+
+        Ok((
+            part1,
+            part2.context("Couldn't find directory at least {min_delete} large")?,
+        ))
+    }
+}
+
+impl Day for Day7 {
+    const NAME: &'static str = "Day 7: No Space Left On Device 💾 🔋";
+    const INPUT: &'static str = "res/day7.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day7_1.txt",
+        part1: Some("95437"),
+        part2: Some("24933642"),
+    }];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
+        let lines = input.lines().collect::<Vec<_>>();
 
         let mut current_directory = vec![];
         let mut directories: HashMap<String, Directory> = HashMap::new();
@@ -31,8 +79,8 @@ impl Day7 {
             } else if lines[curr_line] == "$ ls" {
                 curr_line += 1;
                 while curr_line < lines.len() {
-                    if let Some((size, filename)) =
-                        sscanf::scanf!(lines[curr_line], "{} {}", u32, String).ok()
+                    if let Ok((size, filename)) =
+                        sscanf::scanf!(lines[curr_line], "{} {}", u32, String)
                     {
                         directories
                             .entry(current_directory.concat())
@@ -54,60 +102,21 @@ impl Day7 {
                     }
                 }
             } else {
-                bail!("Unexpected command found: '{}'", lines[0]);
+                return Err(input
+                    .error(lines[curr_line], "a '$ cd DIR' or '$ ls' command")
+                    .into());
             }
         }
 
         Ok(Self { directories })
     }
 
-    fn directory_size(&self, directory: &str) -> u32 {
-        let Some(directory) = self.directories.get(directory) else {
-            return 0
-        };
-
-        let mut size = directory.files.values().sum();
-
-        for subdir in directory.subdirectories.iter() {
-            size += self.directory_size(subdir);
-        }
-
-        size
-    }
-
-    fn both(&self) -> anyhow::Result<(u32, u32)> {
-        let min_delete = 30000000 - (70000000 - self.directory_size("//"));
-
-        let mut part1 = 0;
-        let mut part2 = None;
-
-        for dir in self.directories.keys() {
-            if dir != "//" {
-                let size = self.directory_size(dir);
-
-                if size <= 100_000 {
-                    part1 += size;
-                }
-
-                if size >= min_delete && part2.map(|prev| prev > size).unwrap_or(true) {
-                    part2 = Some(size);
-                }
-            }
-        }
-
-        Ok((
-            part1,
-            part2.context("Couldn't find directory at least {min_delete} large")?,
-        ))
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(self.both()?.0.to_string())
     }
-}
-
-impl Day for Day7 {
-    const NAME: &'static str = "Day 7: No Space Left On Device 💾 🔋";
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let (part1, part2) = Self::load("res/day7.txt")?.both()?;
-        Ok((part1.to_string(), part2.to_string()))
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.both()?.1.to_string())
     }
 }
 
@@ -1,110 +1,42 @@
-use std::collections::HashSet;
-
-use anyhow::Context;
-
-use crate::Day;
+use crate::{dir::Dir, grid::Grid, input::Input, Day, Example};
 
 pub struct Day8 {
-    trees: Vec<Vec<u8>>,
+    trees: Grid<u8>,
 }
 
 impl Day8 {
-    fn load(path: &str) -> anyhow::Result<Self> {
-        let mut trees = vec![];
-
-        for line in std::fs::read_to_string(path)?.lines() {
-            let mut tree_line = vec![];
-            for c in line.chars() {
-                tree_line.push(c.to_digit(10).context("Not a base 10 digit")? as u8);
-            }
-
-            trees.push(tree_line);
-        }
-
-        Ok(Self { trees })
+    pub fn part1(&self) -> usize {
+        self.trees
+            .iter()
+            .filter(|&(pos, height)| {
+                Dir::ALL
+                    .into_iter()
+                    .any(|dir| self.trees.ray(pos, dir).all(|(_, tree)| tree < height))
+            })
+            .count()
     }
 
-    fn part1(&self) -> usize {
-        let mut visible_trees = vec![];
-
-        for line in 0..self.trees.len() {
-            let line_iterator = (0..self.trees[line].len()).map(|column| (line, column));
-            visible_trees.append(&mut self.part1_line(line_iterator.clone()));
-            visible_trees.append(&mut self.part1_line(line_iterator.rev()));
-        }
-
-        for column in 0..self.trees[0].len() {
-            let column_iterator = (0..self.trees.len()).map(|line| (line, column));
-            visible_trees.append(&mut self.part1_line(column_iterator.clone()));
-            visible_trees.append(&mut self.part1_line(column_iterator.rev()));
-        }
-
-        visible_trees.into_iter().collect::<HashSet<_>>().len()
+    pub fn part2(&self) -> usize {
+        self.trees
+            .positions()
+            .map(|pos| {
+                Dir::ALL
+                    .into_iter()
+                    .map(|dir| self.viewing_distance(pos, dir))
+                    .product()
+            })
+            .max()
+            .unwrap_or_default()
     }
 
-    fn part2(&self) -> usize {
-        let mut best = 0;
-        let n = self.trees.len();
-        let m = self.trees[0].len();
-        for curr_i in 0..n {
-            for curr_j in 0..m {
-                let curr_height = self.trees[curr_i][curr_j];
-
-                // north
-                let north = self.part2_line(
-                    (0..curr_i).rev().zip(std::iter::repeat(curr_j)),
-                    curr_height,
-                );
-
-                // south
-                let south =
-                    self.part2_line((curr_i + 1..n).zip(std::iter::repeat(curr_j)), curr_height);
-
-                // west
-                let west = self.part2_line(
-                    (0..curr_j)
-                        .rev()
-                        .zip(std::iter::repeat(curr_i))
-                        .map(|(j, i)| (i, j)),
-                    curr_height,
-                );
-
-                // east
-                let east =
-                    self.part2_line(std::iter::repeat(curr_i).zip(curr_j + 1..m), curr_height);
-
-                let scenic_score = north * south * west * east;
-
-                best = std::cmp::max(scenic_score, best);
-            }
-        }
-
-        best
-    }
-
-    fn part1_line(&self, trees: impl Iterator<Item = (usize, usize)>) -> Vec<(usize, usize)> {
-        let mut visible_trees = vec![];
-        let mut max = None;
-
-        for (i, j) in trees {
-            let tree_height = self.trees[i][j];
-
-            if max.map_or(true, |max| self.trees[i][j] > max) {
-                visible_trees.push((i, j));
-                max = Some(tree_height);
-            }
-        }
-
-        visible_trees
-    }
-
-    fn part2_line(&self, trees: impl Iterator<Item = (usize, usize)>, height: u8) -> usize {
+    /// Number of trees seen from `pos` looking towards `dir`, up to the first one as tall
+    fn viewing_distance(&self, pos: (usize, usize), dir: Dir) -> usize {
         let mut cnt = 0;
 
-        for (i, j) in trees {
+        for (_, tree) in self.trees.ray(pos, dir) {
             cnt += 1;
 
-            if self.trees[i][j] >= height {
+            if *tree >= self.trees[pos] {
                 break;
             }
         }
@@ -115,23 +47,28 @@ impl Day8 {
 
 impl Day for Day8 {
     const NAME: &'static str = "Day 8: Treetop Tree House 🌳🏡🌳";
+    const INPUT: &'static str = "res/day8.txt";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "res/examples/day8_1.txt",
+        part1: Some("21"),
+        part2: Some("8"),
+    }];
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let day = Self::load("res/day8.txt")?;
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let input = Input::read(path)?;
 
-        Ok((day.part1().to_string(), day.part2().to_string()))
+        Ok(Self {
+            trees: Grid::parse(&input, input.text(), "a tree height digit", |c| {
+                c.to_digit(10).map(|height| height as u8)
+            })?,
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example() {
-        let day = Day8::load("res/day8_example.txt").unwrap();
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(Self::part1(self).to_string())
+    }
 
-        assert_eq!(day.part1(), 21);
-        assert_eq!(day.part2(), 8);
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(Self::part2(self).to_string())
     }
 }
@@ -1,36 +1,18 @@
-use std::collections::HashSet;
+use anyhow::bail;
 
-use anyhow::{bail, Context};
+use crate::{dir::Dir, grid::SparseGrid, input::Input, Day, Example};
 
-use crate::{dir::Dir, Day};
-
-pub struct Day9;
+pub struct Day9 {
+    moves: Vec<(Dir, usize)>,
+}
 
 impl Day9 {
-    fn both_parts(path: &str) -> anyhow::Result<(usize, usize)> {
-        // parse
-        let mut moves: Vec<(Dir, usize)> = vec![];
-        for line in std::fs::read_to_string(path)?.lines() {
-            let mut split = line.split_whitespace();
-
-            moves.push((
-                split
-                    .next()
-                    .and_then(|d| d.parse().ok())
-                    .context("Couldn't parse direction")?,
-                split
-                    .next()
-                    .and_then(|d| d.parse().ok())
-                    .context("Couldn't parse # of steps")?,
-            ));
-        }
-
-        // solve
-        let mut knots = vec![(0, 0); 10];
-        let mut visited_part1 = HashSet::from([(0, 0)]);
-        let mut visited_part2 = HashSet::from([(0, 0)]);
+    pub fn both_parts(&self) -> anyhow::Result<(usize, usize)> {
+        let mut knots = [(0, 0); 10];
+        let mut visited_part1 = SparseGrid::from_iter([((0, 0), ())]);
+        let mut visited_part2 = SparseGrid::from_iter([((0, 0), ())]);
 
-        for (dir, steps) in moves {
+        for &(dir, steps) in self.moves.iter() {
             let step = dir.forward();
             for _ in 0..steps {
                 knots[0].0 += step.0;
@@ -55,8 +37,8 @@ impl Day9 {
                     }
                 }
 
-                visited_part1.insert(knots[1]);
-                visited_part2.insert(knots[9]);
+                visited_part1.insert(knots[1], ());
+                visited_part2.insert(knots[9], ());
             }
         }
 
@@ -66,27 +48,43 @@ impl Day9 {
 
 impl Day for Day9 {
     const NAME: &'static str = "Day 9: Rope Bridge 🪢🪢🪢";
+    const INPUT: &'static str = "res/day9.txt";
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "res/examples/day9_1.txt",
+            part1: Some("13"),
+            part2: Some("1"),
+        },
+        Example {
+            input: "res/examples/day9_2.txt",
+            part1: None,
+            part2: Some("36"),
+        },
+    ];
+
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let mut moves = vec![];
+        let input = Input::read(path)?;
+        for line in input.lines() {
+            let mut split = line.split_whitespace();
+            let (Some(dir), Some(steps), None) = (split.next(), split.next(), split.next()) else {
+                return Err(input.error(line, "a move, like 'R 4'").into());
+            };
 
-    fn solve() -> anyhow::Result<(String, String)> {
-        let (part1, part2) = Self::both_parts("res/day9.txt")?;
+            moves.push((
+                input.parse(dir, "'U', 'R', 'D' or 'L'")?,
+                input.parse(steps, "a number of steps")?,
+            ));
+        }
 
-        Ok((part1.to_string(), part2.to_string()))
+        Ok(Self { moves })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        Ok(self.both_parts()?.0.to_string())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn examples() {
-        assert_eq!(
-            Day9::both_parts("res/day9_example_small.txt").unwrap(),
-            (13, 1)
-        );
-        assert_eq!(
-            Day9::both_parts("res/day9_example_large.txt").unwrap().1,
-            36
-        );
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.both_parts()?.1.to_string())
     }
 }
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Dir {
     North,
     East,
@@ -23,6 +23,20 @@ impl FromStr for Dir {
 }
 
 impl Dir {
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Offsets of all 8 tiles around a tile, diagonals included
+    pub const AROUND: [(isize, isize); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+    ];
+
     /// Returns (i,j) where:
     /// 0 j →
     /// i
@@ -35,4 +49,40 @@ impl Dir {
             Self::West => (0, -1),
         }
     }
+
+    /// Offsets of the tile straight ahead along with the two diagonally ahead of it
+    pub fn ahead(&self) -> [(isize, isize); 3] {
+        let (i, j) = self.forward();
+
+        // Forward offsets have a single non-zero coordinate, the diagonals vary the other one
+        if i == 0 {
+            [(-1, j), (0, j), (1, j)]
+        } else {
+            [(i, -1), (i, 0), (i, 1)]
+        }
+    }
+
+    /// The direction after a 90° turn counterclockwise
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// The direction after a 90° turn clockwise
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_left().turn_left()
+    }
 }
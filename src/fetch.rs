@@ -0,0 +1,298 @@
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use anyhow::{bail, Context};
+
+/// Where inputs are downloaded from, unless configured otherwise
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2022";
+
+/// Optional config file, holding `session = "..."` and/or `base_url = "..."`
+pub const CONFIG: &str = "aoc.toml";
+
+/// Environment variables taking precedence over the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Where a day's input is cached
+pub fn input_path(day: usize) -> String {
+    format!("res/day{day}.txt")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, so nothing was downloaded
+    Cached,
+    Downloaded,
+}
+
+/// Downloads puzzle inputs (with `curl`) from `{base_url}/day/N/input`
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// Configures the fetcher from the environment, falling back to the config file at `config`
+    /// (which may not exist) and then to the defaults
+    pub fn from_env(config: &str) -> anyhow::Result<Self> {
+        let mut base_url = None;
+        let mut session = None;
+
+        match std::fs::read_to_string(config) {
+            Ok(content) => {
+                for (key, value) in parse_config(&content)
+                    .with_context(|| format!("Couldn't parse config from '{config}'"))?
+                {
+                    match key.as_str() {
+                        "base_url" => base_url = Some(value),
+                        "session" => session = Some(value),
+                        _ => bail!("Unexpected key '{key}' in '{config}'"),
+                    }
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err).with_context(|| format!("Couldn't read '{config}'")),
+        }
+
+        let base_url = std::env::var(BASE_URL_VAR).ok().or(base_url);
+        let session = std::env::var(SESSION_VAR).ok().or(session);
+
+        Ok(Self::new(
+            base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            session,
+        ))
+    }
+
+    /// Downloads the input of `day` to `path`, unless there's already something there
+    ///
+    /// The trailing newline is stripped, like in the inputs checked in under `res/`.
+    pub fn fetch(&self, day: usize, path: &str) -> anyhow::Result<Fetched> {
+        // Empty files are placeholders, see the `new` subcommand
+        if std::fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.download(day)?;
+
+        if let Some(dir) = Path::new(path).parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Couldn't create '{}'", dir.display()))?;
+        }
+
+        // Written to a temporary file first, so a failure never leaves a partial input behind
+        let tmp = format!("{path}.part");
+        std::fs::write(&tmp, input.trim_end_matches('\n'))
+            .and_then(|_| std::fs::rename(&tmp, path))
+            .with_context(|| format!("Couldn't write input to '{path}'"))?;
+
+        Ok(Fetched::Downloaded)
+    }
+
+    fn download(&self, day: usize) -> anyhow::Result<String> {
+        let session = self.session.as_deref().with_context(|| {
+            format!("No session cookie, set {SESSION_VAR} or 'session' in {CONFIG}")
+        })?;
+
+        if session.is_empty() || !session.chars().all(|c| c.is_ascii_alphanumeric()) {
+            bail!("The session cookie should be the alphanumeric value of the 'session' cookie");
+        }
+
+        let url = format!("{}/day/{day}/input", self.base_url);
+
+        // The cookie goes through stdin rather than the arguments, which other users can see
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--location"])
+            .args(["--config", "-", "--url", &url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Couldn't run curl, is it installed?")?;
+
+        curl.stdin
+            .take()
+            .unwrap()
+            .write_all(
+                format!(
+                    "cookie = \"session={session}\"\nuser-agent = \"adventofcode2022 fetch\"\n"
+                )
+                .as_bytes(),
+            )
+            .context("Couldn't pass the session cookie to curl")?;
+
+        let output = curl.wait_with_output().context("curl didn't finish")?;
+        if !output.status.success() {
+            bail!(
+                "Couldn't download {url}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        String::from_utf8(output.stdout).with_context(|| format!("{url} isn't valid UTF-8"))
+    }
+}
+
+/// Parses `key = "value"` lines, skipping empty lines and `#` comments
+fn parse_config(content: &str) -> anyhow::Result<Vec<(String, String)>> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let (key, value) = line
+                .split_once('=')
+                .with_context(|| format!("Line {}: expected 'key = \"value\"'", i + 1))?;
+            let value = crate::answers::unescape(value.trim())
+                .with_context(|| format!("Line {}: invalid string {}", i + 1, value.trim()))?;
+
+            Ok((key.trim().to_string(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        path::PathBuf,
+        thread::JoinHandle,
+    };
+
+    use super::*;
+
+    /// Serves a single request with `status` and `body`, returning the base URL to reach it and
+    /// a handle to the request's head
+    fn serve_once(status: &str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/2022", listener.local_addr().unwrap());
+        let status = status.to_string();
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut head = String::new();
+            let mut reader = BufReader::new(&mut stream);
+            while reader.read_line(&mut head).unwrap() > 2 {}
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            head
+        });
+
+        (base_url, handle)
+    }
+
+    /// A directory of its own for a test, emptied before and removed after it
+    struct TmpDir(PathBuf);
+
+    impl TmpDir {
+        fn new(test: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("adventofcode2022-{test}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            Self(dir)
+        }
+
+        fn path(&self, name: &str) -> String {
+            self.0.join(name).to_string_lossy().into_owned()
+        }
+    }
+
+    impl Drop for TmpDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Downloads go through curl, without which these tests would only wait on their server
+    fn has_curl() -> bool {
+        let found = Command::new("curl")
+            .arg("--version")
+            .stdout(Stdio::null())
+            .status()
+            .is_ok();
+        if !found {
+            eprintln!("Skipping, curl isn't installed");
+        }
+        found
+    }
+
+    #[test]
+    fn downloads_once() {
+        if !has_curl() {
+            return;
+        }
+
+        let dir = TmpDir::new("downloads_once");
+        let path = dir.path("day1.txt");
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let fetcher = Fetcher::new(&base_url, Some("53616c7465645f5f".to_string()));
+
+        assert_eq!(fetcher.fetch(1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000");
+
+        let head = server.join().unwrap();
+        assert!(
+            head.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"),
+            "{head}"
+        );
+        assert!(
+            head.contains("Cookie: session=53616c7465645f5f\r\n"),
+            "{head}"
+        );
+
+        // The server is gone, so this would fail if it tried to download again
+        assert_eq!(fetcher.fetch(1, &path).unwrap(), Fetched::Cached);
+    }
+
+    #[test]
+    fn failures() {
+        if !has_curl() {
+            return;
+        }
+
+        let dir = TmpDir::new("failures");
+        let path = dir.path("day2.txt");
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+
+        let fetcher = Fetcher::new(&base_url, Some("123abc".to_string()));
+        assert!(fetcher.fetch(2, &path).is_err());
+        assert!(!Path::new(&path).exists());
+        server.join().unwrap();
+
+        assert!(Fetcher::new(&base_url, None).fetch(2, &path).is_err());
+        assert!(Fetcher::new(&base_url, Some("\"; rm".to_string()))
+            .fetch(2, &path)
+            .is_err());
+    }
+
+    #[test]
+    fn config() {
+        assert_eq!(
+            parse_config("# comment\n\nsession = \"abc\"\nbase_url = \"http://localhost\"")
+                .unwrap(),
+            [
+                ("session".to_string(), "abc".to_string()),
+                ("base_url".to_string(), "http://localhost".to_string()),
+            ]
+        );
+        assert!(parse_config("session = abc").is_err());
+    }
+}
@@ -0,0 +1,483 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    dir::Dir,
+    input::{char_at, Input, InputError},
+};
+
+/// A rectangular map of tiles, where (i,j) is:
+/// 0 j →
+/// i
+/// ↓
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    /// Row by row
+    tiles: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            tiles: vec![tile; height * width],
+            height,
+            width,
+        }
+    }
+
+    /// Parses `text` (a slice of `input`) with a row per line, `tile` telling what each character
+    /// is, if it's one of the `expected` ones
+    pub fn parse(
+        input: &Input,
+        text: &str,
+        expected: &str,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, InputError> {
+        let mut tiles = vec![];
+        let mut height = 0;
+        let mut width = None;
+
+        for line in text.lines() {
+            for (j, c) in line.char_indices() {
+                tiles.push(tile(c).ok_or_else(|| input.error(char_at(line, j), expected))?);
+            }
+            height += 1;
+
+            let len = tiles.len() - width.unwrap_or(0) * (height - 1);
+            if *width.get_or_insert(len) != len {
+                return Err(input.error(line, format!("{} tiles", width.unwrap())));
+            }
+        }
+
+        Ok(Self {
+            tiles,
+            height,
+            width: width.unwrap_or(0),
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, (i, j): (usize, usize)) -> Option<&T> {
+        if i < self.height && j < self.width {
+            Some(&self.tiles[i * self.width + j])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (i, j): (usize, usize)) -> Option<&mut T> {
+        if i < self.height && j < self.width {
+            Some(&mut self.tiles[i * self.width + j])
+        } else {
+            None
+        }
+    }
+
+    /// The position at `offset` from `(i, j)`, if it's still in the grid
+    pub fn offset(
+        &self,
+        (i, j): (usize, usize),
+        (di, dj): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let i = i.checked_add_signed(di).filter(|&i| i < self.height)?;
+        let j = j.checked_add_signed(dj).filter(|&j| j < self.width)?;
+        Some((i, j))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// Every tile along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.tiles.iter())
+    }
+
+    /// The (up to) 4 positions next to `pos`, in the order of [`Dir::ALL`]
+    pub fn neighbours(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.forward()))
+    }
+
+    /// The (up to) 8 positions around `pos`, diagonals included
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir::AROUND
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.tiles[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` would panic on an empty width
+        (0..self.height).map(|i| self.row(i))
+    }
+
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            j < self.width,
+            "Column {j} is out of a {}x{} grid",
+            self.height,
+            self.width
+        );
+        self.tiles.iter().skip(j).step_by(self.width)
+    }
+
+    /// The tiles from `pos` (excluded) in the direction `dir`, up to the edge of the grid
+    pub fn ray(&self, pos: (usize, usize), dir: Dir) -> impl Iterator<Item = ((usize, usize), &T)> {
+        std::iter::successors(self.offset(pos, dir.forward()), move |&pos| {
+            self.offset(pos, dir.forward())
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Rows become columns and columns rows
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            tiles: (0..self.width).flat_map(|j| self.col(j).cloned()).collect(),
+            height: self.width,
+            width: self.height,
+        }
+    }
+
+    /// Rotates the grid by 90° clockwise
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            tiles: (0..self.width)
+                .flat_map(|j| {
+                    let col: Vec<_> = self.col(j).cloned().collect();
+                    col.into_iter().rev()
+                })
+                .collect(),
+            height: self.width,
+            width: self.height,
+        }
+    }
+
+    /// Adds a row at the bottom
+    ///
+    /// Panics if it doesn't have as many tiles as the other rows.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.tiles.len();
+        self.tiles.extend(row);
+        assert_eq!(self.tiles.len() - len, self.width, "Wrong row length");
+        self.height += 1;
+    }
+
+    /// Removes the first `n` rows
+    pub fn remove_rows(&mut self, n: usize) {
+        let n = n.min(self.height);
+        self.tiles.drain(..n * self.width);
+        self.height -= n;
+    }
+
+    /// Draws each tile with `glyph`, a line per row
+    pub fn render(&self, glyph: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&glyph).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is out of a {}x{} grid", self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is out of a {height}x{width} grid"))
+    }
+}
+
+/// Signed coordinates of a [`SparseGrid`], component-wise
+pub trait Point: Copy + Eq + Hash + 'static {
+    /// Offsets to the points sharing a side with any point
+    const SIDES: &'static [Self];
+
+    fn splat(n: isize) -> Self;
+    fn offset(self, by: Self) -> Self;
+    fn min_each(self, other: Self) -> Self;
+    fn max_each(self, other: Self) -> Self;
+
+    /// Every point of the box from `min` to `max`, both included
+    fn region(min: Self, max: Self) -> Box<dyn Iterator<Item = Self>>;
+
+    fn within(self, min: Self, max: Self) -> bool {
+        self.max_each(min) == self && self.min_each(max) == self
+    }
+}
+
+impl Point for (isize, isize) {
+    const SIDES: &'static [Self] = &[(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+    fn splat(n: isize) -> Self {
+        (n, n)
+    }
+
+    fn offset(self, by: Self) -> Self {
+        (self.0 + by.0, self.1 + by.1)
+    }
+
+    fn min_each(self, other: Self) -> Self {
+        (self.0.min(other.0), self.1.min(other.1))
+    }
+
+    fn max_each(self, other: Self) -> Self {
+        (self.0.max(other.0), self.1.max(other.1))
+    }
+
+    fn region(min: Self, max: Self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((min.0..=max.0).flat_map(move |i| (min.1..=max.1).map(move |j| (i, j))))
+    }
+}
+
+impl Point for (isize, isize, isize) {
+    const SIDES: &'static [Self] = &[
+        (-1, 0, 0),
+        (1, 0, 0),
+        (0, -1, 0),
+        (0, 1, 0),
+        (0, 0, -1),
+        (0, 0, 1),
+    ];
+
+    fn splat(n: isize) -> Self {
+        (n, n, n)
+    }
+
+    fn offset(self, by: Self) -> Self {
+        (self.0 + by.0, self.1 + by.1, self.2 + by.2)
+    }
+
+    fn min_each(self, other: Self) -> Self {
+        (
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+        )
+    }
+
+    fn max_each(self, other: Self) -> Self {
+        (
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+        )
+    }
+
+    fn region(min: Self, max: Self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((min.0..=max.0).flat_map(move |x| {
+            (min.1..=max.1).flat_map(move |y| (min.2..=max.2).map(move |z| (x, y, z)))
+        }))
+    }
+}
+
+/// An unbounded map of tiles, only storing the ones that were set, and keeping track of the box
+/// around them
+#[derive(Clone, Debug)]
+pub struct SparseGrid<P, T> {
+    tiles: HashMap<P, T>,
+    /// Smallest and largest coordinates of the tiles
+    bounds: Option<(P, P)>,
+}
+
+impl<P: Point, T> Default for SparseGrid<P, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Point, T> SparseGrid<P, T> {
+    pub fn new() -> Self {
+        Self {
+            tiles: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Sets the tile at `pos`, returning the one that was there
+    pub fn insert(&mut self, pos: P, tile: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min_each(pos), max.max_each(pos)),
+            None => (pos, pos),
+        });
+
+        self.tiles.insert(pos, tile)
+    }
+
+    pub fn get(&self, pos: P) -> Option<&T> {
+        self.tiles.get(&pos)
+    }
+
+    pub fn contains(&self, pos: P) -> bool {
+        self.tiles.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Smallest and largest coordinates of the tiles, unless there are none
+    pub fn bounds(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+
+    /// Every tile that was set along with its position, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.tiles.iter().map(|(&pos, tile)| (pos, tile))
+    }
+
+    /// The positions sharing a side with `pos`, whether they're set or not
+    pub fn neighbours(&self, pos: P) -> impl Iterator<Item = P> {
+        P::SIDES.iter().map(move |&side| pos.offset(side))
+    }
+
+    /// Every position of the box from `min` to `max` (included) along with its tile if it's set
+    pub fn region(&self, min: P, max: P) -> impl Iterator<Item = (P, Option<&T>)> {
+        P::region(min, max).map(|pos| (pos, self.get(pos)))
+    }
+}
+
+impl<T> SparseGrid<(isize, isize), T> {
+    /// Draws the box around the tiles with `glyph`, a line per row
+    pub fn render(&self, glyph: impl Fn(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        (min.0..=max.0)
+            .map(|i| {
+                self.region((i, min.1), (i, max.1))
+                    .map(|(_, tile)| glyph(tile))
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
+
+impl<P: Point, T> FromIterator<(P, T)> for SparseGrid<P, T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, tile) in iter {
+            grid.insert(pos, tile);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid() {
+        let input = Input::new("grid.txt", "123\n456".to_string());
+        let grid = Grid::parse(&input, input.text(), "a digit", |c| c.to_digit(10)).unwrap();
+
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.col(1).collect::<Vec<_>>(), [&2, &5]);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(
+            grid.ray((1, 2), Dir::West).collect::<Vec<_>>(),
+            [((1, 1), &5), ((1, 0), &4)]
+        );
+
+        let glyph = |n: &u32| char::from_digit(*n, 10).unwrap();
+        assert_eq!(grid.transpose().render(glyph), "14\n25\n36\n");
+        assert_eq!(grid.rotate().render(glyph), "41\n52\n63\n");
+        assert_eq!(
+            grid.rotate().rotate().rotate().rotate().render(glyph),
+            "123\n456\n"
+        );
+
+        let input = Input::new("grid.txt", "12\n345".to_string());
+        let err = Grid::parse(&input, input.text(), "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "2 tiles"));
+
+        let input = Input::new("grid.txt", "123\n4x6".to_string());
+        let err = Grid::parse(&input, input.text(), "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 2, 1));
+        assert_eq!(err.expected, "a digit");
+
+        let input = Input::new("grid.txt", String::new());
+        let grid = Grid::parse(&input, input.text(), "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.height(), grid.width()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.get((0, 0)), None);
+        assert_eq!(grid.render(|n| char::from_digit(*n, 10).unwrap()), "");
+    }
+
+    #[test]
+    #[should_panic = "Column 3 is out of a 2x3 grid"]
+    fn col_out_of_bounds() {
+        let grid = Grid::new(2, 3, 0);
+        let _ = grid.col(3);
+    }
+
+    #[test]
+    fn sparse_grid() {
+        let mut grid = SparseGrid::new();
+        grid.insert((-1, 2), 'a');
+        grid.insert((1, 0), 'b');
+
+        assert_eq!(grid.bounds(), Some(((-1, 0), (1, 2))));
+        assert_eq!(grid.get((1, 0)), Some(&'b'));
+        assert_eq!(
+            grid.neighbours((1, 1))
+                .filter(|&n| grid.contains(n))
+                .count(),
+            1
+        );
+        assert_eq!(grid.render(|tile| *tile.unwrap_or(&'.')), "..a\n...\nb..\n");
+
+        let cube: SparseGrid<_, ()> = [((0, 0, 0), ()), ((1, 1, 1), ())].into_iter().collect();
+        assert_eq!(cube.region((0, 0, 0), (1, 1, 1)).count(), 8);
+        assert!((0, 1, 0).within((0, 0, 0), (1, 1, 1)));
+        assert!(!(0, 2, 0).within((0, 0, 0), (1, 1, 1)));
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A puzzle input, which can point at where it went wrong with an [`InputError`]
+pub struct Input {
+    path: String,
+    text: String,
+}
+
+impl Input {
+    /// Reads the input at `path`, or stdin if `path` is `-`
+    pub fn read(path: &str) -> anyhow::Result<Self> {
+        Ok(Self::new(path, crate::read_input(path)?))
+    }
+
+    pub fn new(path: &str, text: String) -> Self {
+        let path = if path == "-" { "<stdin>" } else { path };
+
+        Self {
+            path: path.to_string(),
+            text,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// An empty slice at the end of the text, to point at something missing
+    pub fn end(&self) -> &str {
+        &self.text[self.text.len()..]
+    }
+
+    /// An error pointing at `at`, which should be a slice of [`Self::text`]
+    pub fn error(&self, at: &str, expected: impl Display) -> InputError {
+        let start = self.offset_of(at);
+        let line_start = self.text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.text[start..]
+            .find('\n')
+            .map_or(self.text.len(), |i| start + i);
+
+        // Only underlines up to the end of the line
+        let end = (start + at.len()).min(line_end);
+
+        InputError {
+            path: self.path.clone(),
+            line: self.text[..start].matches('\n').count() + 1,
+            column: self.text[line_start..start].chars().count() + 1,
+            len: self.text[start..end].chars().count(),
+            content: self.text[line_start..line_end].to_string(),
+            expected: expected.to_string(),
+            reason: None,
+        }
+    }
+
+    /// Parses `s` (a slice of [`Self::text`]), pointing at it if it isn't a valid `T`
+    pub fn parse<T>(&self, s: &str, expected: impl Display) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        s.parse()
+            .map_err(|err| self.error(s, expected).because(err))
+    }
+
+    fn offset_of(&self, at: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let ptr = at.as_ptr() as usize;
+
+        if (start..=start + self.text.len()).contains(&ptr) {
+            ptr - start
+        } else {
+            self.text.find(at).unwrap_or(0)
+        }
+    }
+}
+
+/// The character of `s` starting at byte `j`, as a slice to point at
+pub fn char_at(s: &str, j: usize) -> &str {
+    let len = s[j..].chars().next().map_or(0, char::len_utf8);
+    &s[j..j + len]
+}
+
+/// An error in a puzzle input, carrying where it is and what was expected there
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputError {
+    pub path: String,
+    /// Starting from 1
+    pub line: usize,
+    /// Starting from 1, counted in characters
+    pub column: usize,
+    /// Number of characters at fault
+    pub len: usize,
+    /// The whole line at fault
+    pub content: String,
+    pub expected: String,
+    pub reason: Option<String>,
+}
+
+impl InputError {
+    /// Adds the underlying reason why the input is wrong
+    pub fn because(mut self, reason: impl Display) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    /// Renders the error like a compiler would:
+    ///
+    /// ```text
+    /// error: expected a number of calories (invalid digit found in string)
+    ///  --> res/day1.txt:3:1
+    ///   |
+    /// 3 | 12a4
+    ///   | ^^^^
+    /// ```
+    pub fn render(&self) -> String {
+        let gutter = self.line.to_string().len();
+        let reason = self
+            .reason
+            .as_ref()
+            .map(|reason| format!(" ({reason})"))
+            .unwrap_or_default();
+
+        format!(
+            "error: expected {}{reason}\n{:gutter$}--> {}:{}:{}\n{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+            self.expected,
+            "",
+            self.path,
+            self.line,
+            self.column,
+            "",
+            self.line,
+            self.content,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len.max(1)),
+        )
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: expected {}",
+            self.path, self.line, self.column, self.expected
+        )?;
+
+        if let Some(reason) = &self.reason {
+            write!(f, " ({reason})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates() {
+        let input = Input::new("res/day1.txt", "1000\n2000\n\n3ä0x\n".to_string());
+        let line = input.lines().nth(3).unwrap();
+
+        let err = input.parse::<u32>(&line[4..], "a number").unwrap_err();
+        assert_eq!(
+            err,
+            InputError {
+                path: "res/day1.txt".to_string(),
+                line: 4,
+                column: 4,
+                len: 1,
+                content: "3ä0x".to_string(),
+                expected: "a number".to_string(),
+                reason: Some("invalid digit found in string".to_string()),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "res/day1.txt:4:4: expected a number (invalid digit found in string)"
+        );
+        assert_eq!(
+            err.render(),
+            "error: expected a number (invalid digit found in string)\n --> res/day1.txt:4:4\n  |\n4 | 3ä0x\n  |    ^"
+        );
+
+        let err = input.error(input.end(), "more");
+        assert_eq!((err.line, err.column, err.len), (5, 1, 0));
+        assert_eq!(
+            Input::new("-", String::new()).error("", "a line").path,
+            "<stdin>"
+        );
+    }
+}
@@ -0,0 +1,373 @@
+//! Solutions for [advent of code 2022](https://adventofcode.com/2022), with every day's solver
+//! registered in [`DAYS`]
+
+pub mod answers;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod dir;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod pf;
+pub mod scaffold;
+pub mod snafu;
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    io::Read,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+
+/// Registers each day's solver under the day's number, along with a test checking its examples
+macro_rules! days {
+    ($($n:literal => $module:ident::$day:ident,)*) => {
+        pub const DAYS: &[Entry] = &[$(Entry {
+            day: $n,
+            name: <$module::$day as Day>::NAME,
+            input: <$module::$day as Day>::INPUT,
+            solve: <$module::$day as Day>::solve,
+            solve_n_print: <$module::$day as Day>::solve_n_print,
+        },)*];
+
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                #[test]
+                fn $module() {
+                    check_examples::<$module::$day>();
+                }
+            )*
+
+            #[test]
+            fn all_declared() {
+                let declared: Vec<_> = [$(<$module::$day as Day>::EXAMPLES,)*]
+                    .into_iter()
+                    .flatten()
+                    .map(|example| example.input)
+                    .collect();
+
+                check_declared(&declared);
+            }
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+}
+
+/// A registered day, see [`DAYS`]
+pub struct Entry {
+    pub day: usize,
+    pub name: &'static str,
+    pub input: &'static str,
+    pub solve: fn(&str, Option<Part>) -> anyhow::Result<Solution>,
+    pub solve_n_print: fn(&str, Option<Part>) -> anyhow::Result<Solution>,
+}
+
+/// Picks the registered days out of a selection like `all`, `3`, `3-7`, `1,5,12` or `1,3-5`
+pub fn select_days(selection: &str) -> anyhow::Result<Vec<&'static Entry>> {
+    if selection == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+
+    parse_days(selection)?
+        .into_iter()
+        .map(|day| {
+            DAYS.iter()
+                .find(|entry| entry.day == day)
+                .with_context(|| format!("Day {day} isn't solved yet, run 'list' to see which are"))
+        })
+        .collect()
+}
+
+/// Parses a selection of days like `3`, `3-7`, `1,5,12` or `1,3-5`, solved or not, without
+/// duplicates
+pub fn parse_days(selection: &str) -> anyhow::Result<Vec<usize>> {
+    let mut days = vec![];
+    let mut seen = HashSet::new();
+    for item in selection.split(',') {
+        let parse = |day: &str| {
+            day.trim()
+                .parse::<usize>()
+                .with_context(|| format!("'{item}' is not a valid day or range of days"))
+        };
+
+        let (from, to) = match item.split_once('-') {
+            Some((from, to)) => (parse(from)?, parse(to)?),
+            None => (parse(item)?, parse(item)?),
+        };
+
+        if from > to {
+            anyhow::bail!("'{item}' is an empty range of days");
+        }
+        // Checked before expanding the range, which could otherwise be huge
+        if let Some(day) = [from, to].into_iter().find(|day| !(1..=25).contains(day)) {
+            anyhow::bail!("There's no day {day} in advent of code");
+        }
+
+        days.extend((from..=to).filter(|&day| seen.insert(day)));
+    }
+
+    Ok(days)
+}
+
+/// Reads a puzzle input, either from the file at `path` or from stdin if `path` is `-`
+pub fn read_input(path: &str) -> anyhow::Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Couldn't read input from stdin")?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("Couldn't read input from '{path}'"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(anyhow::format_err!("'{s}' is not a valid part")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub trait Day: Sized {
+    const NAME: &'static str;
+    /// Path of the puzzle input used when none is given
+    const INPUT: &'static str;
+
+    /// Examples from the puzzle's description, checked by the test suite
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn load(path: &str) -> anyhow::Result<Self>;
+
+    fn part1(&self) -> anyhow::Result<String>;
+
+    fn part2(&self) -> anyhow::Result<String>;
+
+    /// Solves the given part, or both of them if `part` is `None`
+    fn solve(path: &str, part: Option<Part>) -> anyhow::Result<Solution> {
+        let start = Instant::now();
+        let day = Self::load(path)?;
+        let load_time = start.elapsed();
+
+        let mut answers = vec![];
+        for p in [Part::One, Part::Two] {
+            if part.is_none_or(|part| part == p) {
+                let start = Instant::now();
+                let answer = match p {
+                    Part::One => day.part1()?,
+                    Part::Two => day.part2()?,
+                };
+                answers.push((p, answer, start.elapsed()));
+            }
+        }
+
+        Ok(Solution { load_time, answers })
+    }
+
+    /// Solves like [`Day::solve`], then prints the answers with their timings or why it failed
+    fn solve_n_print(path: &str, part: Option<Part>) -> anyhow::Result<Solution> {
+        let result = Self::solve(path, part);
+        match &result {
+            Ok(solution) => solution.print(Self::NAME),
+            Err(err) => print_failure(Self::NAME, err),
+        }
+        result
+    }
+}
+
+/// Answers to a puzzle, along with how long it took to load its input and solve each part
+pub struct Solution {
+    pub load_time: Duration,
+    pub answers: Vec<(Part, String, Duration)>,
+}
+
+impl Solution {
+    /// Prints the answers under the day's `name`, along with the timings
+    pub fn print(&self, name: &str) {
+        println!("{name} [parsed in {:.2?}]", self.load_time);
+        for (i, (part, answer, time)) in self.answers.iter().enumerate() {
+            let branch = if i + 1 == self.answers.len() {
+                '└'
+            } else {
+                '├'
+            };
+            println!("{branch} Part {part} [{time:.2?}]: {answer}");
+        }
+    }
+}
+
+/// Prints why the day `name` couldn't be solved, rendering errors in its input like a compiler
+/// would
+pub fn print_failure(name: &str, err: &anyhow::Error) {
+    match err.downcast_ref::<input::InputError>() {
+        Some(err) => println!("Failed to solve '{name}':\n{}", err.render()),
+        None => println!("Failed to solve '{name}':\n{err:#}"),
+    }
+}
+
+/// An example input from a puzzle's description, along with the answers it should give
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+/// Solves each of a day's examples, failing with every wrong answer at once
+#[cfg(test)]
+fn check_examples<D: Day>() {
+    let mut failures = vec![];
+
+    for example in D::EXAMPLES {
+        let day = match D::load(example.input) {
+            Ok(day) => day,
+            Err(err) => {
+                failures.push(format!("{}: {err:#}", example.input));
+                continue;
+            }
+        };
+
+        for (part, expected) in [(Part::One, example.part1), (Part::Two, example.part2)] {
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            let answer = match part {
+                Part::One => day.part1(),
+                Part::Two => day.part2(),
+            };
+
+            match answer {
+                Ok(answer) if answer == expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{} part {part}: expected {expected:?}, got {answer:?}",
+                    example.input
+                )),
+                Err(err) => failures.push(format!("{} part {part}: {err:#}", example.input)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Makes sure no file in `res/examples` is left out of its day's examples
+#[cfg(test)]
+fn check_declared(declared: &[&str]) {
+    let mut undeclared: Vec<_> = std::fs::read_dir("res/examples")
+        .unwrap()
+        .map(|entry| {
+            format!(
+                "res/examples/{}",
+                entry.unwrap().file_name().to_string_lossy()
+            )
+        })
+        .filter(|path| !declared.contains(&path.as_str()))
+        .collect();
+    undeclared.sort();
+
+    assert!(undeclared.is_empty(), "Undeclared examples: {undeclared:?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_days("3-7").unwrap(), [3, 4, 5, 6, 7]);
+        assert_eq!(parse_days("1,5,12").unwrap(), [1, 5, 12]);
+        assert_eq!(parse_days("1,3-5,4").unwrap(), [1, 3, 4, 5]);
+
+        for selection in ["7-3", "3-", "-3", "x", "0", "1-26", "1-99999999999"] {
+            assert!(parse_days(selection).is_err(), "{selection}");
+        }
+
+        let days: Vec<_> = select_days("2,4-5")
+            .unwrap()
+            .iter()
+            .map(|e| e.day)
+            .collect();
+        assert_eq!(days, [2, 4, 5]);
+        assert_eq!(select_days("all").unwrap().len(), DAYS.len());
+
+        let Err(err) = select_days("25-26") else {
+            panic!("Day 26 was selected");
+        };
+        assert_eq!(err.to_string(), "There's no day 26 in advent of code");
+    }
+}
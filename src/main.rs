@@ -1,99 +1,690 @@
-#![feature(int_roundings)]
-
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod dir;
-mod pf;
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use adventofcode2022::{
+    answers::{escape, Answers},
+    fetch::{self, Fetched, Fetcher},
+    parse_days, print_failure, scaffold, select_days, Entry, Part, Solution, DAYS,
+};
+
+/// Where `verify` looks for the expected answers
+const ANSWERS: &str = "res/answers.toml";
+
+/// How often `watch` checks for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    /// One JSON object per line for each day
+    Json,
+}
+
+enum Mode {
+    Solve { format: Format },
+    Bench { runs: usize },
+    Verify { record: bool },
+    Fetch,
+    New,
+    Watch,
+}
 
 fn main() {
-    let arg = std::env::args().nth(1).map(|arg| arg.to_lowercase());
-
-    match arg.as_deref() {
-        Some("1") => day1::Day1::solve_n_print(),
-        Some("2") => day2::Day2::solve_n_print(),
-        Some("3") => day3::Day3::solve_n_print(),
-        Some("4") => day4::Day4::solve_n_print(),
-        Some("5") => day5::Day5::solve_n_print(),
-        Some("6") => day6::Day6::solve_n_print(),
-        Some("7") => day7::Day7::solve_n_print(),
-        Some("8") => day8::Day8::solve_n_print(),
-        Some("9") => day9::Day9::solve_n_print(),
-        Some("10") => day10::Day10::solve_n_print(),
-        Some("11") => day11::Day11::solve_n_print(),
-        Some("12") => day12::Day12::solve_n_print(),
-        Some("13") => day13::Day13::solve_n_print(),
-        Some("14") => day14::Day14::solve_n_print(),
-        Some("15") => day15::Day15::solve_n_print(),
-        Some("16") => day16::Day16::solve_n_print(),
-        Some("17") => day17::Day17::solve_n_print(),
-        Some("18") => day18::Day18::solve_n_print(),
-        Some("19") => day19::Day19::solve_n_print(),
-        Some("20") => day20::Day20::solve_n_print(),
-        Some("all") => {
-            day1::Day1::solve_n_print();
-            day2::Day2::solve_n_print();
-            day3::Day3::solve_n_print();
-            day4::Day4::solve_n_print();
-            day5::Day5::solve_n_print();
-            day6::Day6::solve_n_print();
-            day7::Day7::solve_n_print();
-            day8::Day8::solve_n_print();
-            day9::Day9::solve_n_print();
-            day10::Day10::solve_n_print();
-            day11::Day11::solve_n_print();
-            day12::Day12::solve_n_print();
-            day13::Day13::solve_n_print();
-            day14::Day14::solve_n_print();
-            day15::Day15::solve_n_print();
-            day16::Day16::solve_n_print();
-            day17::Day17::solve_n_print();
-            day18::Day18::solve_n_print();
-            day19::Day19::solve_n_print();
-            day20::Day20::solve_n_print()
-        }
-        _ => {
-            usage();
+    let mut command = None;
+    let mut arg = None;
+    let mut input = None;
+    let mut part = None;
+    let mut runs = None;
+    let mut record = false;
+    let mut format = Format::Text;
+    let mut jobs = std::thread::available_parallelism().map_or(1, usize::from);
+
+    let mut args = std::env::args().skip(1);
+    while let Some(next) = args.next() {
+        if next == "--part" {
+            match args.next().as_deref().map(str::parse) {
+                Some(Ok(p)) => part = Some(p),
+                _ => exit_with_usage(),
+            }
+        } else if next == "--runs" {
+            match args.next().as_deref().map(str::parse) {
+                Some(Ok(r)) if r > 0 => runs = Some(r),
+                _ => exit_with_usage(),
+            }
+        } else if next == "--format" {
+            match args.next().as_deref() {
+                Some("text") => format = Format::Text,
+                Some("json") => format = Format::Json,
+                _ => exit_with_usage(),
+            }
+        } else if next == "--jobs" {
+            match args.next().as_deref().map(str::parse) {
+                Some(Ok(j)) if j > 0 => jobs = j,
+                _ => exit_with_usage(),
+            }
+        } else if next == "--record" {
+            record = true;
+        } else if ["bench", "verify", "fetch", "new", "watch"].contains(&next.as_str())
+            && command.is_none()
+            && arg.is_none()
+        {
+            command = Some(next);
+        } else if arg.is_none() {
+            arg = Some(next.to_lowercase());
+        } else if input.is_none() {
+            input = Some(next);
+        } else {
+            exit_with_usage();
+        }
+    }
+
+    let mode = match (command.as_deref(), runs, record) {
+        (None, None, false) => Mode::Solve { format },
+        (Some("bench"), runs, false) if format == Format::Text => Mode::Bench {
+            runs: runs.unwrap_or(10),
+        },
+        (Some("verify"), None, record) if input.is_none() && format == Format::Text => {
+            Mode::Verify { record }
+        }
+        (Some("fetch"), None, false)
+            if input.is_none() && part.is_none() && format == Format::Text =>
+        {
+            Mode::Fetch
+        }
+        (Some("new"), None, false) if part.is_none() && format == Format::Text => Mode::New,
+        (Some("watch"), None, false) if format == Format::Text => Mode::Watch,
+        _ => exit_with_usage(),
+    };
+
+    let Some(arg) = arg else {
+        exit_with_usage();
+    };
+
+    if let Mode::New = mode {
+        let (Ok(day), Some(title)) = (arg.parse(), input) else {
+            exit_with_usage();
+        };
+
+        match scaffold::new_day(Path::new("."), day, &title) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Wrote '{}'", path.display());
+                }
+                return;
+            }
+            Err(err) => {
+                println!("{err:#}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Mode::Fetch = mode {
+        // Inputs can be fetched before the day is solved
+        let days = match arg.as_str() {
+            "all" => Ok((1..=25).collect()),
+            selection => parse_days(selection),
+        };
+
+        match days.and_then(|days| fetch_n_print(&days)) {
+            Ok(true) => return,
+            Ok(false) => std::process::exit(1),
+            Err(err) => {
+                println!("{err:#}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if arg == "list" {
+        for entry in DAYS {
+            println!("{:>2}  {}", entry.day, entry.name);
+        }
+        return;
+    }
+
+    let entries = match select_days(&arg) {
+        Ok(entries) => entries,
+        Err(err) => {
+            println!("{err:#}");
             std::process::exit(1);
         }
+    };
+
+    if entries.len() > 1 && input.is_some() {
+        exit_with_usage();
+    }
+
+    match mode {
+        Mode::Solve { format } => {
+            let mut failures = vec![];
+
+            if format == Format::Text && (jobs == 1 || entries.len() == 1) {
+                // Nothing runs alongside, so each day can print as soon as it's solved
+                for &entry in &entries {
+                    let path = input.as_deref().unwrap_or(entry.input);
+                    let result = std::panic::catch_unwind(|| (entry.solve_n_print)(path, part))
+                        .unwrap_or_else(|panic| {
+                            let err = panicked(panic);
+                            print_failure(entry.name, &err);
+                            Err(err)
+                        });
+
+                    if let Err(err) = result {
+                        failures.push((entry, err));
+                    }
+                }
+            } else {
+                solve_days(
+                    &entries,
+                    input.as_deref(),
+                    |_| part,
+                    jobs,
+                    |entry, result| {
+                        print_solution(entry, &result, format);
+
+                        if let Err(err) = result {
+                            failures.push((entry, err));
+                        }
+                    },
+                );
+            }
+
+            if entries.len() > 1 && format == Format::Text {
+                print_summary(entries.len(), &failures);
+            }
+
+            if !failures.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Mode::Bench { runs } => {
+            // stdin can only be read once
+            if input.as_deref() == Some("-") {
+                exit_with_usage();
+            }
+
+            print_bench_header();
+
+            let mut failures = vec![];
+            for entry in entries.iter() {
+                if let Err(err) = bench_n_print(entry, input.as_deref(), part, runs) {
+                    failures.push((*entry, err));
+                }
+            }
+
+            if entries.len() > 1 {
+                print_summary(entries.len(), &failures);
+            }
+
+            if !failures.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Mode::Verify { record } => {
+            let mut answers = Answers::load(ANSWERS).unwrap_or_else(|err| {
+                println!("{err:#}");
+                std::process::exit(1);
+            });
+
+            // Without --record, parts with no answer to check against aren't worth solving, some
+            // of them take ages
+            let mut to_solve = vec![];
+            let mut solved_parts = HashMap::new();
+            for &entry in &entries {
+                let parts: Vec<_> = requested_parts(part)
+                    .filter(|&part| record || answers.get(entry.day, part).is_some())
+                    .collect();
+
+                match parts[..] {
+                    [] => continue,
+                    [part] => solved_parts.insert(entry.day, Some(part)),
+                    _ => solved_parts.insert(entry.day, None),
+                };
+                to_solve.push(entry);
+            }
+
+            let mut all_passed = true;
+            let mut unsolved = entries.iter().peekable();
+            let mut verify = |entry, result| {
+                all_passed &= verify_n_print(entry, result, &mut answers, part, record);
+            };
+            let nothing = || {
+                Ok(Solution {
+                    load_time: Duration::ZERO,
+                    answers: vec![],
+                })
+            };
+
+            solve_days(
+                &to_solve,
+                None,
+                |entry| solved_parts[&entry.day],
+                jobs,
+                |entry, result| {
+                    // Reporting the days before this one that weren't solved at all first
+                    while let Some(skipped) = unsolved.next_if(|e| e.day != entry.day) {
+                        verify(skipped, nothing());
+                    }
+                    unsolved.next();
+
+                    verify(entry, result);
+                },
+            );
+            for skipped in unsolved {
+                verify(skipped, nothing());
+            }
+
+            if record {
+                if let Err(err) = answers.save(ANSWERS) {
+                    println!("{err:#}");
+                    std::process::exit(1);
+                }
+            } else if !all_passed {
+                std::process::exit(1);
+            }
+        }
+        Mode::Watch => {
+            // stdin can only be read once
+            let ([entry], false) = (&entries[..], input.as_deref() == Some("-")) else {
+                exit_with_usage();
+            };
+
+            watch(entry, input.as_deref(), part);
+        }
+        Mode::Fetch | Mode::New => unreachable!("Handled before selecting solved days"),
+    }
+}
+
+fn exit_with_usage() -> ! {
+    println!("USAGE:");
+    println!("    adventofcode2022 DAYS [INPUT] [--part 1 | 2] [--format text | json] [--jobs N]");
+    println!("    adventofcode2022 bench DAYS [INPUT] [--part 1 | 2] [--runs N]");
+    println!("    adventofcode2022 verify DAYS [--part 1 | 2] [--record] [--jobs N]");
+    println!("    adventofcode2022 fetch DAYS");
+    println!("    adventofcode2022 new DAY TITLE");
+    println!("    adventofcode2022 watch DAY [INPUT] [--part 1 | 2]");
+    println!("    adventofcode2022 list");
+    println!("\nDAYS is a day like 4, a range like 3-7, a list like 1,5,12 or all");
+    println!("INPUT defaults to res/dayN.txt, '-' reads it from stdin, only for a single day");
+    println!(
+        "--jobs defaults to the number of CPUs, days are solved in parallel but reported in order"
+    );
+    println!("verify checks answers against {ANSWERS}, --record overwrites them");
+    println!(
+        "fetch downloads missing inputs, using the session cookie from ${} or {}",
+        fetch::SESSION_VAR,
+        fetch::CONFIG
+    );
+    println!("watch reruns the day whenever res/dayN.txt or INPUT change");
+    println!("new generates src/dayN.rs with empty input and example files, and registers it");
+    std::process::exit(1);
+}
+
+fn print_solution(entry: &Entry, result: &anyhow::Result<Solution>, format: Format) {
+    match format {
+        Format::Text => match result {
+            Ok(solution) => solution.print(entry.name),
+            Err(err) => print_failure(entry.name, err),
+        },
+        Format::Json => println!("{}", to_json(entry, result)),
+    }
+}
+
+/// Solves the given days (only the part `part` picks for each, or both) on up to `jobs` threads,
+/// handing each result to `f` in the same order as `entries` as soon as it (and every one before
+/// it) is available
+fn solve_days(
+    entries: &[&'static Entry],
+    input: Option<&str>,
+    part: impl Fn(&Entry) -> Option<Part> + Sync,
+    jobs: usize,
+    mut f: impl FnMut(&'static Entry, anyhow::Result<Solution>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..std::cmp::min(jobs, entries.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            let part = &part;
+
+            scope.spawn(move || {
+                while let Some(entry) = entries.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let path = input.unwrap_or(entry.input);
+                    let part = part(entry);
+                    let result = std::panic::catch_unwind(|| (entry.solve)(path, part))
+                        .unwrap_or_else(|panic| Err(panicked(panic)));
+
+                    if sender.send((entry.day, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        // Only the workers should be holding a sender, so that the receiver stops once they're done
+        drop(sender);
+
+        let mut pending = HashMap::new();
+        let mut entries = entries.iter().peekable();
+
+        for (day, result) in receiver {
+            pending.insert(day, result);
+
+            while let Some(result) = entries.peek().and_then(|e| pending.remove(&e.day)) {
+                f(entries.next().unwrap(), result);
+            }
+        }
+    });
+}
+
+/// Turns the payload of a day's panic into an error
+fn panicked(panic: Box<dyn Any + Send>) -> anyhow::Error {
+    let msg = panic
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+    anyhow::format_err!("Panicked: {msg}")
+}
+
+/// Lists the days that failed, with their full chain of errors
+fn print_summary(n_days: usize, failures: &[(&Entry, anyhow::Error)]) {
+    println!("\nSolved {} out of {n_days} days", n_days - failures.len());
+
+    for (i, (entry, err)) in failures.iter().enumerate() {
+        let (branch, indent) = if i + 1 == failures.len() {
+            ('└', ' ')
+        } else {
+            ('├', '│')
+        };
+
+        println!("{branch} Failed '{}':", entry.name);
+        for (j, cause) in err.chain().enumerate() {
+            if j == 0 {
+                println!("{indent}   {cause}");
+            } else {
+                println!("{indent}   caused by: {cause}");
+            }
+        }
     }
 }
 
-fn usage() {
-    println!("USAGE:\n    adventofcode2022 DAY | ALL");
+/// Serializes a day's result as a single-line JSON object, with timings in nanoseconds:
+///
+/// `{"day":1,"name":"...","load_ns":12,"parts":[{"part":1,"answer":"...","ns":34}],"error":null}`
+fn to_json(entry: &Entry, result: &anyhow::Result<Solution>) -> String {
+    let (load_ns, parts, error) = match result {
+        Ok(solution) => (
+            solution.load_time.as_nanos().to_string(),
+            solution
+                .answers
+                .iter()
+                .map(|(part, answer, time)| {
+                    format!(
+                        "{{\"part\":{part},\"answer\":{},\"ns\":{}}}",
+                        escape(answer),
+                        time.as_nanos()
+                    )
+                })
+                .collect::<Vec<_>>(),
+            "null".to_string(),
+        ),
+        Err(err) => ("null".to_string(), vec![], escape(&format!("{err:#}"))),
+    };
+
+    format!(
+        "{{\"day\":{},\"name\":{},\"load_ns\":{load_ns},\"parts\":[{}],\"error\":{error}}}",
+        entry.day,
+        escape(entry.name),
+        parts.join(","),
+    )
 }
 
-pub trait Day {
-    const NAME: &'static str;
+fn print_bench_header() {
+    println!(
+        "{:<8} │ {:>10} │ {:>10} │ {:>10}",
+        "", "Min", "Median", "Max"
+    );
+}
 
-    fn solve() -> anyhow::Result<(String, String)>;
+/// Solves the puzzle `runs` times and prints the min/median/max time of each stage
+fn bench_n_print(
+    entry: &Entry,
+    path: Option<&str>,
+    part: Option<Part>,
+    runs: usize,
+) -> anyhow::Result<()> {
+    let mut load_times = vec![];
+    let mut part_times: Vec<(Part, Vec<Duration>)> = vec![];
 
-    fn solve_n_print() {
-        match Self::solve() {
-            Ok((part1, part2)) => {
-                println!("{}\n├ Part 1: {part1}\n└ Part 2: {part2}", Self::NAME)
+    for _ in 0..runs {
+        match (entry.solve)(path.unwrap_or(entry.input), part) {
+            Ok(solution) => {
+                load_times.push(solution.load_time);
+                for (i, (part, _, time)) in solution.answers.into_iter().enumerate() {
+                    if i == part_times.len() {
+                        part_times.push((part, vec![]));
+                    }
+                    part_times[i].1.push(time);
+                }
             }
             Err(err) => {
-                println!("Failed to solve '{}':\n{err}", Self::NAME);
-                std::process::exit(1);
+                print_failure(entry.name, &err);
+                return Err(err);
             }
         }
     }
+
+    println!("{}", entry.name);
+    print_bench_row("Parse", load_times);
+    for (part, times) in part_times {
+        print_bench_row(&format!("Part {part}"), times);
+    }
+
+    Ok(())
+}
+
+fn print_bench_row(stage: &str, mut times: Vec<Duration>) {
+    times.sort();
+
+    println!(
+        "{stage:<8} │ {:>10} │ {:>10} │ {:>10}",
+        format!("{:.2?}", times[0]),
+        format!("{:.2?}", times[times.len() / 2]),
+        format!("{:.2?}", times[times.len() - 1]),
+    );
+}
+
+/// Compares the day's answers to the recorded ones (or records them), returning whether they all
+/// matched
+/// Checks the answers of `solution` against the recorded ones, the parts it lacks being reported
+/// as missing
+fn verify_n_print(
+    entry: &Entry,
+    result: anyhow::Result<Solution>,
+    answers: &mut Answers,
+    part: Option<Part>,
+    record: bool,
+) -> bool {
+    let solution = match result {
+        Ok(solution) => solution,
+        Err(err) => {
+            print_failure(entry.name, &err);
+            return false;
+        }
+    };
+
+    let mut passed = true;
+
+    println!("{}", entry.name);
+    let parts: Vec<_> = requested_parts(part).collect();
+    for (i, &part) in parts.iter().enumerate() {
+        let branch = if i + 1 == parts.len() { '└' } else { '├' };
+
+        let Some((_, answer, _)) = solution.answers.iter().find(|(p, _, _)| *p == part) else {
+            println!("{branch} Part {part}: missing");
+            continue;
+        };
+        let answer = answer.clone();
+
+        let status = match answers.get(entry.day, part) {
+            Some(expected) if expected == answer => "pass".to_string(),
+            Some(expected) if record => format!("recorded {answer} (was {expected})"),
+            Some(expected) => {
+                passed = false;
+                format!("FAIL (expected {expected}, got {answer})")
+            }
+            None if record => format!("recorded {answer}"),
+            None => format!("missing (got {answer})"),
+        };
+
+        if record {
+            answers.insert(entry.day, part, answer);
+        }
+
+        println!("{branch} Part {part}: {status}");
+    }
+
+    passed
+}
+
+/// Both parts, unless only `part` was asked for
+fn requested_parts(part: Option<Part>) -> impl Iterator<Item = Part> {
+    [Part::One, Part::Two]
+        .into_iter()
+        .filter(move |&p| part.is_none_or(|part| part == p))
+}
+
+/// Downloads the inputs of `days` that aren't there yet, returning whether they all made it
+fn fetch_n_print(days: &[usize]) -> anyhow::Result<bool> {
+    let fetcher = Fetcher::from_env(fetch::CONFIG)?;
+    let mut all_fetched = true;
+
+    for &day in days {
+        let path = fetch::input_path(day);
+
+        match fetcher.fetch(day, &path) {
+            Ok(Fetched::Cached) => println!("Day {day}: already in '{path}'"),
+            Ok(Fetched::Downloaded) => println!("Day {day}: downloaded to '{path}'"),
+            Err(err) => {
+                println!("Day {day}: {err:#}");
+                all_fetched = false;
+            }
+        }
+    }
+
+    Ok(all_fetched)
+}
+
+/// Reruns the day whenever its input files change, until interrupted
+fn watch(entry: &'static Entry, input: Option<&str>, part: Option<Part>) -> ! {
+    let mut previous = BTreeMap::new();
+    let mut last_seen = None;
+
+    loop {
+        let seen = watched_files(entry.day, input);
+
+        if last_seen.as_ref() != Some(&seen) {
+            if last_seen.is_some() {
+                println!("\n{}\n", "─".repeat(40));
+            }
+            last_seen = Some(seen);
+
+            solve_days(
+                &[entry],
+                input,
+                |_| part,
+                1,
+                |entry, result| print_watched(entry, result, &mut previous),
+            );
+            println!("\nWatching for changes, press Ctrl+C to stop");
+        }
+
+        std::thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+/// The files `watch` keeps an eye on, along with when they were last modified (if they exist)
+fn watched_files(day: usize, input: Option<&str>) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut paths = vec![PathBuf::from(format!("res/day{day}.txt"))];
+    paths.extend(input.map(PathBuf::from));
+    paths.dedup();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Prints the solution like `print_solution`, noting how each answer changed since the last run
+fn print_watched(
+    entry: &Entry,
+    result: anyhow::Result<Solution>,
+    previous: &mut BTreeMap<Part, String>,
+) {
+    let solution = match result {
+        Ok(solution) => solution,
+        Err(err) => {
+            print_failure(entry.name, &err);
+            return;
+        }
+    };
+
+    println!("{} [parsed in {:.2?}]", entry.name, solution.load_time);
+    let n_answers = solution.answers.len();
+    for (i, (part, answer, time)) in solution.answers.into_iter().enumerate() {
+        let branch = if i + 1 == n_answers { '└' } else { '├' };
+
+        let change = match previous.get(&part) {
+            Some(before) if *before == answer => "unchanged".to_string(),
+            Some(before) => format!("was {before}"),
+            None => "new".to_string(),
+        };
+
+        println!("{branch} Part {part} [{time:.2?}]: {answer} ({change})");
+        previous.insert(part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let entry = DAYS.iter().find(|entry| entry.day == 10).unwrap();
+
+        let solution = Solution {
+            load_time: Duration::from_nanos(1200),
+            answers: vec![
+                (Part::One, "13140".to_string(), Duration::from_nanos(30)),
+                (
+                    Part::Two,
+                    "\n##..\n#..#\n".to_string(),
+                    Duration::from_nanos(40),
+                ),
+            ],
+        };
+        assert_eq!(
+            to_json(entry, &Ok(solution)),
+            r###"{"day":10,"name":"Day 10: Cathode-Ray Tube 💡 📡","load_ns":1200,"parts":[{"part":1,"answer":"13140","ns":30},{"part":2,"answer":"\n##..\n#..#\n","ns":40}],"error":null}"###
+        );
+
+        let err = anyhow::anyhow!("expected \"noop\"").context("Couldn't load \\input");
+        assert_eq!(
+            to_json(entry, &Err(err)),
+            r#"{"day":10,"name":"Day 10: Cathode-Ray Tube 💡 📡","load_ns":null,"parts":[],"error":"Couldn't load \\input: expected \"noop\""}"#
+        );
+    }
 }
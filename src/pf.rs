@@ -1,8 +1,12 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::RandomState, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+};
 
 pub fn a_star<S, E, N, H>(starts: HashSet<S>, end: E, nexts: N, heur: H) -> Option<(Vec<S>, usize)>
 where
-    S: Clone + std::hash::Hash + PartialEq + Eq + PartialOrd + Ord,
+    S: Clone + Hash + PartialEq + Eq + PartialOrd + Ord,
     E: Fn(&S) -> bool,
     N: Fn(&S) -> HashSet<(S, usize)>,
     H: Fn(&S) -> usize,
@@ -42,6 +46,445 @@ where
     None
 }
 
+/// Same as [`a_star`], but only needs states to be hashable, and is much faster on big states
+///
+/// Each state is stored once, and referred to by its index everywhere else. The open set is a
+/// binary heap, where a state is pushed again whenever a shorter way to it is found, and the
+/// outdated entries are skipped when popped. With a heuristic of `|_| 0`, this is Dijkstra.
+pub fn a_star_heap<S, E, N, I, H>(
+    starts: impl IntoIterator<Item = S>,
+    end: E,
+    nexts: N,
+    heur: H,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Hash + Eq,
+    E: Fn(&S) -> bool,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: Fn(&S) -> usize,
+{
+    let (search, end) = HeapSearch::run(starts, end, nexts, heur);
+    let end = end?;
+
+    Some((
+        search
+            .path(end)
+            .into_iter()
+            .map(|id| search.states.get(id).clone())
+            .collect(),
+        search.dists[end],
+    ))
+}
+
+/// Distances from the starts of a search to every state it reached
+#[derive(Debug)]
+pub struct Distances<S> {
+    pub dists: HashMap<S, usize>,
+    /// State each state was reached from on a shortest path, starts have none
+    pub prevs: HashMap<S, S>,
+}
+
+impl<S: Clone + Hash + Eq> Distances<S> {
+    pub fn get(&self, state: &S) -> Option<usize> {
+        self.dists.get(state).copied()
+    }
+
+    /// A shortest path from one of the starts to `to`, both included
+    pub fn path_to(&self, to: &S) -> Option<Vec<S>> {
+        if !self.dists.contains_key(to) {
+            return None;
+        }
+
+        let mut path = vec![to.clone()];
+        while let Some(prev) = self.prevs.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Distances from `starts` to every state reachable through `nexts`, each step costing 1
+pub fn bfs<S, N, I>(starts: impl IntoIterator<Item = S>, nexts: N) -> Distances<S>
+where
+    S: Clone + Hash + Eq,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut dists = HashMap::new();
+    let mut prevs = HashMap::new();
+    let mut to_visit = VecDeque::new();
+
+    for start in starts {
+        if dists.insert(start.clone(), 0).is_none() {
+            to_visit.push_back(start);
+        }
+    }
+
+    while let Some(curr) = to_visit.pop_front() {
+        let dist = dists[&curr] + 1;
+
+        for next in nexts(&curr) {
+            if !dists.contains_key(&next) {
+                dists.insert(next.clone(), dist);
+                prevs.insert(next.clone(), curr.clone());
+                to_visit.push_back(next);
+            }
+        }
+    }
+
+    Distances { dists, prevs }
+}
+
+/// Distances from `starts` to every state reachable through `nexts`, which returns the cost of
+/// each step like with [`a_star`]
+pub fn dijkstra<S, N, I>(starts: impl IntoIterator<Item = S>, nexts: N) -> Distances<S>
+where
+    S: Clone + Hash + Eq,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let (search, _) = HeapSearch::run(starts, |_| false, nexts, |_| 0);
+
+    let state = |id| search.states.get(id).clone();
+    Distances {
+        dists: (0..search.dists.len())
+            .map(|id| (state(id), search.dists[id]))
+            .collect(),
+        prevs: (0..search.prevs.len())
+            .filter_map(|id| Some((state(id), state(search.prevs[id]?))))
+            .collect(),
+    }
+}
+
+/// Every state reachable from `starts` through `nexts`, starts included
+pub fn flood_fill<S, N, I>(starts: impl IntoIterator<Item = S>, nexts: N) -> HashSet<S>
+where
+    S: Clone + Hash + Eq,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = HashSet::new();
+    let mut to_visit = vec![];
+
+    for start in starts {
+        if reached.insert(start.clone()) {
+            to_visit.push(start);
+        }
+    }
+
+    while let Some(curr) = to_visit.pop() {
+        for next in nexts(&curr) {
+            if !reached.contains(&next) {
+                reached.insert(next.clone());
+                to_visit.push(next);
+            }
+        }
+    }
+
+    reached
+}
+
+/// Every shortest path from one of `starts` to a state satisfying `end`, along with their cost
+///
+/// Every step has to cost at least 1. Beware, there can be exponentially many such paths.
+pub fn all_shortest_paths<S, E, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    end: E,
+    nexts: N,
+) -> Option<(Vec<Vec<S>>, usize)>
+where
+    S: Clone + Hash + Eq,
+    E: Fn(&S) -> bool,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut states = Interner::default();
+    // Indexed by state, with every state each one is reached from on a shortest path
+    let mut dists: Vec<usize> = vec![];
+    let mut prevs: Vec<Vec<usize>> = vec![];
+    let mut to_visit = BinaryHeap::new();
+
+    for start in starts {
+        let (id, new) = states.intern(start);
+        if new {
+            dists.push(0);
+            prevs.push(vec![]);
+            to_visit.push(Reverse((0, id)));
+        }
+    }
+
+    let mut ends = vec![];
+    while let Some(Reverse((dist, curr))) = to_visit.pop() {
+        if dist > dists[curr] {
+            continue;
+        } else if ends.first().is_some_and(|&end| dist > dists[end]) {
+            break;
+        } else if end(states.get(curr)) {
+            ends.push(curr);
+            continue;
+        }
+
+        for (next, cost) in nexts(states.get(curr)) {
+            let next_dist = dist + cost;
+            let (id, new) = states.intern(next);
+
+            if new {
+                dists.push(next_dist);
+                prevs.push(vec![curr]);
+            } else if dists[id] > next_dist {
+                dists[id] = next_dist;
+                prevs[id] = vec![curr];
+            } else {
+                if dists[id] == next_dist && !prevs[id].contains(&curr) {
+                    prevs[id].push(curr);
+                }
+                continue;
+            }
+
+            to_visit.push(Reverse((next_dist, id)));
+        }
+    }
+
+    let cost = dists[*ends.first()?];
+
+    // Walking back from the ends, so partial paths are reversed
+    let mut paths = vec![];
+    let mut partials: Vec<Vec<usize>> = ends.into_iter().map(|end| vec![end]).collect();
+    while let Some(partial) = partials.pop() {
+        let prevs = &prevs[*partial.last().unwrap()];
+
+        if prevs.is_empty() {
+            paths.push(
+                partial
+                    .into_iter()
+                    .rev()
+                    .map(|id| states.get(id).clone())
+                    .collect(),
+            );
+        } else {
+            for &prev in prevs {
+                let mut partial = partial.clone();
+                partial.push(prev);
+                partials.push(partial);
+            }
+        }
+    }
+
+    Some((paths, cost))
+}
+
+/// The `k` shortest paths without loops from `start` to a state satisfying `end` (Yen's
+/// algorithm), shortest first and along with their cost
+///
+/// Paths costing the same are sorted by number of steps.
+pub fn k_shortest_paths<S, E, N, I>(start: S, end: E, nexts: N, k: usize) -> Vec<(Vec<S>, usize)>
+where
+    S: Clone + Hash + Eq,
+    E: Fn(&S) -> bool,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let step_cost = |from: &S, to: &S| {
+        nexts(from)
+            .into_iter()
+            .filter(|(next, _)| next == to)
+            .map(|(_, cost)| cost)
+            .min()
+            .unwrap()
+    };
+
+    let mut found: Vec<(Vec<S>, usize)> = vec![];
+    let mut candidates: Vec<_> = a_star_heap([start], &end, &nexts, |_| 0)
+        .into_iter()
+        .collect();
+
+    while found.len() < k {
+        let Some(best) = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (path, cost))| (*cost, path.len()))
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        found.push(candidates.swap_remove(best));
+
+        // Deviating from the latest path at each of its states (the spur), by forbidding the
+        // steps that the paths found so far took from there, and going back to the states before
+        let (path, _) = found.last().unwrap();
+        let mut root_cost = 0;
+
+        for i in 0..path.len() - 1 {
+            let spur = &path[i];
+            let root: HashSet<&S> = path[..i].iter().collect();
+            let taken: HashSet<&S> = found
+                .iter()
+                .filter(|(other, _)| other.len() > i + 1 && other[..=i] == path[..=i])
+                .map(|(other, _)| &other[i + 1])
+                .collect();
+
+            let (root, taken) = (&root, &taken);
+            let deviation = a_star_heap(
+                [spur.clone()],
+                &end,
+                |curr: &S| {
+                    let from_spur = curr == spur;
+                    nexts(curr).into_iter().filter(move |(next, _)| {
+                        !(root.contains(next) || from_spur && taken.contains(next))
+                    })
+                },
+                |_| 0,
+            );
+
+            if let Some((deviation, cost)) = deviation {
+                let candidate: Vec<S> = path[..i].iter().cloned().chain(deviation).collect();
+                if !candidates.iter().any(|(other, _)| *other == candidate) {
+                    candidates.push((candidate, root_cost + cost));
+                }
+            }
+
+            root_cost += step_cost(&path[i], &path[i + 1]);
+        }
+    }
+
+    found
+}
+
+/// A search over interned states with a binary heap, see [`a_star_heap`]
+struct HeapSearch<S> {
+    states: Interner<S>,
+    /// Indexed by state
+    dists: Vec<usize>,
+    /// Indexed by state, starts have no previous state
+    prevs: Vec<Option<usize>>,
+}
+
+impl<S: Hash + Eq> HeapSearch<S> {
+    /// Searches until a state satisfying `end` is reached (returning it), or there's nothing
+    /// left to visit
+    fn run<E, N, I, H>(
+        starts: impl IntoIterator<Item = S>,
+        end: E,
+        nexts: N,
+        heur: H,
+    ) -> (Self, Option<usize>)
+    where
+        E: Fn(&S) -> bool,
+        N: Fn(&S) -> I,
+        I: IntoIterator<Item = (S, usize)>,
+        H: Fn(&S) -> usize,
+    {
+        let mut search = Self {
+            states: Interner::default(),
+            dists: vec![],
+            prevs: vec![],
+        };
+        let mut to_visit = BinaryHeap::new();
+
+        for start in starts {
+            let (id, new) = search.states.intern(start);
+            if new {
+                search.dists.push(0);
+                search.prevs.push(None);
+                to_visit.push(Reverse((heur(search.states.get(id)), 0, id)));
+            }
+        }
+
+        while let Some(Reverse((_, dist, curr))) = to_visit.pop() {
+            if dist > search.dists[curr] {
+                continue;
+            }
+
+            if end(search.states.get(curr)) {
+                return (search, Some(curr));
+            }
+
+            for (next, cost) in nexts(search.states.get(curr)) {
+                let next_dist = dist + cost;
+                let (id, new) = search.states.intern(next);
+
+                if new {
+                    search.dists.push(next_dist);
+                    search.prevs.push(Some(curr));
+                } else if search.dists[id] > next_dist {
+                    search.dists[id] = next_dist;
+                    search.prevs[id] = Some(curr);
+                } else {
+                    continue;
+                }
+
+                to_visit.push(Reverse((
+                    next_dist + heur(search.states.get(id)),
+                    next_dist,
+                    id,
+                )));
+            }
+        }
+
+        (search, None)
+    }
+
+    /// Path from one of the starts to `to`, both included
+    fn path(&self, to: usize) -> Vec<usize> {
+        let mut path = vec![to];
+        while let Some(prev) = self.prevs[*path.last().unwrap()] {
+            path.push(prev);
+        }
+        path.reverse();
+
+        path
+    }
+}
+
+/// Stores each distinct state once, handing out indices (in insertion order) to refer to them
+struct Interner<S> {
+    states: Vec<S>,
+    /// Latest state with a given hash
+    latest: HashMap<u64, usize>,
+    /// For each state, the previous state with the same hash
+    collisions: Vec<Option<usize>>,
+    hasher: RandomState,
+}
+
+impl<S> Default for Interner<S> {
+    fn default() -> Self {
+        Self {
+            states: vec![],
+            latest: HashMap::new(),
+            collisions: vec![],
+            hasher: RandomState::new(),
+        }
+    }
+}
+
+impl<S: Hash + Eq> Interner<S> {
+    /// Returns the index of `state`, and whether it was new
+    fn intern(&mut self, state: S) -> (usize, bool) {
+        let hash = self.hasher.hash_one(&state);
+
+        let mut same_hash = self.latest.get(&hash).copied();
+        while let Some(id) = same_hash {
+            if self.states[id] == state {
+                return (id, false);
+            }
+            same_hash = self.collisions[id];
+        }
+
+        let id = self.states.len();
+        self.states.push(state);
+        self.collisions.push(self.latest.insert(hash, id));
+
+        (id, true)
+    }
+
+    fn get(&self, id: usize) -> &S {
+        &self.states[id]
+    }
+}
+
 pub fn neighbours_usize(
     pos: &(usize, usize),
     n: Option<usize>,
@@ -50,25 +493,25 @@ pub fn neighbours_usize(
     let mut ret = vec![];
 
     if let Some(up) = pos.0.checked_sub(1) {
-        if n.map_or(true, |n| up < n) {
+        if n.is_none_or(|n| up < n) {
             ret.push((up, pos.1));
         }
     }
 
     if let Some(down) = pos.0.checked_add(1) {
-        if n.map_or(true, |n| down < n) {
+        if n.is_none_or(|n| down < n) {
             ret.push((down, pos.1));
         }
     }
 
     if let Some(left) = pos.1.checked_sub(1) {
-        if m.map_or(true, |m| left < m) {
+        if m.is_none_or(|m| left < m) {
             ret.push((pos.0, left));
         }
     }
 
     if let Some(right) = pos.1.checked_add(1) {
-        if m.map_or(true, |m| right < m) {
+        if m.is_none_or(|m| right < m) {
             ret.push((pos.0, right));
         }
     }
@@ -86,3 +529,141 @@ pub fn neighhbours_usize_3d(&(i, j, k): &(isize, isize, isize)) -> Vec<(isize, i
         (i, j, k - 1),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example from Wikipedia's article on Yen's algorithm
+    fn yen_graph(node: &char) -> Vec<(char, usize)> {
+        match node {
+            'C' => vec![('D', 3), ('E', 2)],
+            'D' => vec![('F', 4)],
+            'E' => vec![('D', 1), ('F', 2), ('G', 3)],
+            'F' => vec![('G', 2), ('H', 1)],
+            'G' => vec![('H', 2)],
+            _ => vec![],
+        }
+    }
+
+    /// A 4x5 maze, '#' being walls, with a single shortest path to each tile
+    const MAZE: [&str; 4] = ["..#.#", ".##..", "..##.", "#...."];
+
+    fn maze_nexts(pos: &(usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        neighbours_usize(pos, Some(MAZE.len()), Some(MAZE[0].len()))
+            .into_iter()
+            .filter(|&(i, j)| MAZE[i].as_bytes()[j] != b'#')
+    }
+
+    #[test]
+    fn bfs_maze() {
+        let from_corner = bfs([(0, 0)], maze_nexts);
+
+        assert_eq!(from_corner.get(&(0, 0)), Some(0));
+        assert_eq!(from_corner.get(&(3, 1)), Some(4));
+        assert_eq!(from_corner.get(&(0, 3)), Some(11));
+        assert_eq!(from_corner.get(&(2, 2)), None);
+        assert_eq!(
+            from_corner.path_to(&(3, 3)),
+            Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (3, 1), (3, 2), (3, 3)])
+        );
+        assert_eq!(from_corner.path_to(&(1, 1)), None);
+
+        let from_both_ends = bfs([(0, 0), (0, 3)], maze_nexts);
+        assert_eq!(from_both_ends.get(&(2, 4)), Some(3));
+        assert_eq!(from_both_ends.path_to(&(0, 3)), Some(vec![(0, 3)]));
+    }
+
+    #[test]
+    fn flood_fill_maze() {
+        let filled = flood_fill([(0, 0)], maze_nexts);
+        assert_eq!(filled.len(), 13);
+        assert!(filled.contains(&(0, 3)));
+        assert!(!filled.iter().any(|&(i, j)| MAZE[i].as_bytes()[j] == b'#'));
+
+        // Walled off by the rest of the maze
+        let blocked = |pos: &(usize, usize)| maze_nexts(pos).filter(|&(i, _)| i < 2);
+        assert_eq!(
+            flood_fill([(0, 0)], blocked),
+            HashSet::from([(0, 0), (0, 1), (1, 0)])
+        );
+    }
+
+    #[test]
+    fn heap_matches_a_star() {
+        // Uneven costs from 1 to 9, so that the cheapest path isn't a straight one
+        let tile_cost = |(i, j): (usize, usize)| (i * 7 + j * 3) % 9 + 1;
+        let nexts = |pos: &(usize, usize)| -> HashSet<((usize, usize), usize)> {
+            neighbours_usize(pos, Some(12), Some(12))
+                .into_iter()
+                .map(|pos| (pos, tile_cost(pos)))
+                .collect()
+        };
+        let end = |pos: &(usize, usize)| *pos == (11, 11);
+        let heur = |&(i, j): &(usize, usize)| (11 - i) + (11 - j);
+
+        for starts in [vec![(0, 0)], vec![(0, 11), (11, 0)]] {
+            let (_, cost) = a_star(starts.iter().cloned().collect(), end, nexts, heur).unwrap();
+            let (path, heap_cost) = a_star_heap(starts.clone(), end, nexts, heur).unwrap();
+
+            assert_eq!(heap_cost, cost);
+            assert!(starts.contains(&path[0]));
+            assert_eq!(path.last(), Some(&(11, 11)));
+            let path_cost: usize = path[1..].iter().map(|&pos| tile_cost(pos)).sum();
+            assert_eq!(path_cost, cost);
+        }
+
+        assert_eq!(a_star_heap([(0, 0)], |_| false, nexts, |_| 0), None);
+    }
+
+    #[test]
+    fn k_shortest() {
+        let paths: Vec<_> = k_shortest_paths('C', |n| *n == 'H', yen_graph, 4)
+            .into_iter()
+            .map(|(path, cost)| (path.into_iter().collect::<String>(), cost))
+            .collect();
+
+        assert_eq!(
+            paths,
+            [
+                ("CEFH".to_string(), 5),
+                ("CEGH".to_string(), 7),
+                ("CDFH".to_string(), 8),
+                ("CEDFH".to_string(), 8),
+            ]
+        );
+        assert_eq!(
+            k_shortest_paths('C', |n| *n == 'H', yen_graph, 100).len(),
+            7
+        );
+        assert!(k_shortest_paths('H', |n| *n == 'C', yen_graph, 3).is_empty());
+    }
+
+    #[test]
+    fn all_shortest() {
+        let grid = |pos: &(usize, usize)| {
+            neighbours_usize(pos, Some(3), Some(3))
+                .into_iter()
+                .map(|next| (next, 1))
+        };
+
+        let (mut paths, cost) = all_shortest_paths([(0, 0)], |pos| *pos == (1, 2), grid).unwrap();
+        paths.sort();
+        assert_eq!(cost, 3);
+        assert_eq!(
+            paths,
+            [
+                vec![(0, 0), (0, 1), (0, 2), (1, 2)],
+                vec![(0, 0), (0, 1), (1, 1), (1, 2)],
+                vec![(0, 0), (1, 0), (1, 1), (1, 2)],
+            ]
+        );
+
+        let (paths, cost) = all_shortest_paths(['C'], |n| *n == 'H', yen_graph).unwrap();
+        assert_eq!((paths.len(), cost), (1, 5));
+
+        let from_c = dijkstra(['C'], yen_graph);
+        assert_eq!(from_c.get(&'G'), Some(5));
+        assert_eq!(from_c.path_to(&'G'), Some(vec!['C', 'E', 'G']));
+    }
+}
@@ -0,0 +1,222 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+/// Generates the skeleton of a new day under `root`, along with its (empty) input and example
+/// files, and registers it in `src/lib.rs`, returning the paths it created or changed
+pub fn new_day(root: &Path, day: usize, title: &str) -> anyhow::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("There's no day {day} in advent of code");
+    }
+
+    let title = title.trim();
+    if title.is_empty() {
+        bail!("The title can't be empty");
+    }
+
+    let source = root.join(format!("src/day{day}.rs"));
+    if source.exists() {
+        bail!("'{}' already exists", source.display());
+    }
+
+    let lib = root.join("src/lib.rs");
+    let registered = register(
+        &std::fs::read_to_string(&lib)
+            .with_context(|| format!("Couldn't read '{}'", lib.display()))?,
+        day,
+    )
+    .with_context(|| format!("Couldn't register day {day} in '{}'", lib.display()))?;
+
+    std::fs::write(&source, skeleton(day, title))
+        .with_context(|| format!("Couldn't write '{}'", source.display()))?;
+    std::fs::write(&lib, registered)
+        .with_context(|| format!("Couldn't write '{}'", lib.display()))?;
+
+    let mut paths = vec![source, lib];
+
+    // Inputs may have been fetched already
+    for file in [
+        format!("res/day{day}.txt"),
+        format!("res/examples/day{day}_1.txt"),
+    ] {
+        let path = root.join(file);
+        if !path.exists() {
+            std::fs::create_dir_all(path.parent().unwrap())
+                .and_then(|_| std::fs::write(&path, ""))
+                .with_context(|| format!("Couldn't create '{}'", path.display()))?;
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+fn skeleton(day: usize, title: &str) -> String {
+    format!(
+        r#"use crate::{{input::Input, Day, Example}};
+
+pub struct Day{day} {{
+    lines: Vec<String>,
+}}
+
+impl Day{day} {{
+    pub fn part1(&self) -> anyhow::Result<usize> {{
+        // Something to start from
+        Ok(self.lines.len())
+    }}
+
+    pub fn part2(&self) -> anyhow::Result<usize> {{
+        anyhow::bail!("Not solved yet")
+    }}
+}}
+
+impl Day for Day{day} {{
+    const NAME: &'static str = {name:?};
+    const INPUT: &'static str = "res/day{day}.txt";
+    const EXAMPLES: &'static [Example] = &[Example {{
+        input: "res/examples/day{day}_1.txt",
+        part1: None,
+        part2: None,
+    }}];
+
+    fn load(path: &str) -> anyhow::Result<Self> {{
+        Ok(Self {{
+            lines: Input::read(path)?.lines().map(String::from).collect(),
+        }})
+    }}
+
+    fn part1(&self) -> anyhow::Result<String> {{
+        Ok(Self::part1(self)?.to_string())
+    }}
+
+    fn part2(&self) -> anyhow::Result<String> {{
+        Ok(Self::part2(self)?.to_string())
+    }}
+}}
+"#,
+        name = format!("Day {day}: {title}"),
+    )
+}
+
+/// Adds `pub mod dayN;` among the other days' modules (sorted like rustfmt does) and the day to
+/// the `days!` registry (sorted by number)
+fn register(lib: &str, day: usize) -> anyhow::Result<String> {
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+
+    let module = format!("day{day}");
+    let mods: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+            name.strip_prefix("day")?.parse::<usize>().ok()?;
+            Some((i, name))
+        })
+        .collect();
+
+    if mods.iter().any(|(_, name)| *name == module) {
+        bail!("Module '{module}' is already declared");
+    }
+    let at = match mods.iter().find(|(_, name)| *name > module.as_str()) {
+        Some(&(i, _)) => i,
+        None => mods.last().context("No day modules found")?.0 + 1,
+    };
+    lines.insert(at, format!("pub mod {module};"));
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "days! {")
+        .context("No 'days!' registry found")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "}")
+            .context("Unterminated 'days!' registry")?;
+
+    let mut at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let n: usize = line
+            .split_once("=>")
+            .and_then(|(n, _)| n.trim().parse().ok())
+            .with_context(|| format!("Unexpected line in 'days!': '{line}'"))?;
+
+        if n == day {
+            bail!("Day {day} is already registered");
+        } else if n > day {
+            at = i;
+            break;
+        }
+    }
+    lines.insert(at, format!("    {day} => {module}::Day{day},"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod answers;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod dir;
+
+days! {
+    1 => day1::Day1,
+    3 => day3::Day3,
+}
+";
+
+    #[test]
+    fn registers() {
+        assert_eq!(
+            register(LIB, 2).unwrap_err().to_string(),
+            "Module 'day2' is already declared"
+        );
+
+        let registered = register(LIB, 10).unwrap();
+        assert_eq!(
+            registered,
+            LIB.replace("pub mod day1;\n", "pub mod day1;\npub mod day10;\n")
+                .replace(
+                    "    3 => day3::Day3,\n",
+                    "    3 => day3::Day3,\n    10 => day10::Day10,\n"
+                )
+        );
+
+        let registered = register(&registered, 4).unwrap();
+        assert!(registered.contains("pub mod day3;\npub mod day4;\npub mod dir;"));
+        assert!(registered.contains("    4 => day4::Day4,\n    10 => day10::Day10,"));
+    }
+
+    #[test]
+    fn scaffolds() {
+        let root =
+            std::env::temp_dir().join(format!("adventofcode2022-new-{}", std::process::id()));
+        // Leftovers of an earlier run with the same pid would make new_day fail
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/lib.rs"), LIB).unwrap();
+
+        let err = new_day(&root, 2, "Rock Paper Scissors").unwrap_err();
+        assert!(err.to_string().contains("Couldn't register day 2"));
+        assert!(!root.join("src/day2.rs").exists());
+
+        let paths = new_day(&root, 5, "Supply \"Stacks\"").unwrap();
+        assert_eq!(paths.len(), 4);
+        assert!(std::fs::read_to_string(root.join("src/day5.rs"))
+            .unwrap()
+            .contains(r#"const NAME: &'static str = "Day 5: Supply \"Stacks\"";"#));
+        assert_eq!(
+            std::fs::read_to_string(root.join("res/day5.txt")).unwrap(),
+            ""
+        );
+        assert!(root.join("res/examples/day5_1.txt").exists());
+
+        assert!(new_day(&root, 5, "Again").is_err());
+        assert!(new_day(&root, 26, "Too late").is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, Neg},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+
+/// An integer written in SNAFU, i.e. balanced quinary: base 5 with the digits `=` (-2), `-` (-1),
+/// `0`, `1` and `2`, which covers negative numbers without a sign
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Snafu {
+    /// Least significant first, without trailing zeros (so zero has no digits at all)
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    fn from_digits(mut digits: Vec<i8>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        Self { digits }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.digits.last().is_some_and(|d| *d < 0)
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            bail!("A SNAFU number needs at least one digit");
+        }
+
+        let digits = s
+            .chars()
+            .rev()
+            .map(|c| match c {
+                '2' => Ok(2),
+                '1' => Ok(1),
+                '0' => Ok(0),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => bail!("'{c}' is not a SNAFU digit"),
+            })
+            .collect::<anyhow::Result<_>>()
+            .with_context(|| format!("Couldn't parse '{s}' as SNAFU"))?;
+
+        Ok(Self::from_digits(digits))
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+
+        for digit in self.digits.iter().rev() {
+            let c = match digit {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                -2 => '=',
+                _ => unreachable!("SNAFU digits are within -2..=2"),
+            };
+            write!(f, "{c}")?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_int_conversions {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Snafu {
+            fn from(mut n: $int) -> Self {
+                let mut digits = vec![];
+
+                while n != 0 {
+                    let digit = match n.rem_euclid(5) {
+                        d @ 0..=2 => d,
+                        d => d - 5,
+                    };
+                    digits.push(digit as i8);
+                    // Dividing first avoids overflowing on the extremes
+                    n = n.div_euclid(5) + if digit < 0 { 1 } else { 0 };
+                }
+
+                Self { digits }
+            }
+        }
+
+        impl TryFrom<&Snafu> for $int {
+            type Error = anyhow::Error;
+
+            fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+                snafu
+                    .digits
+                    .iter()
+                    .rev()
+                    .try_fold(0 as $int, |n, digit| {
+                        // Moving a 5 between `n` and `digit` when their signs differ keeps
+                        // `n * 5` in range whenever the result is
+                        let (n, digit) = match (n.signum(), digit.signum()) {
+                            (-1, 1) => (n + 1, *digit as $int - 5),
+                            (1, -1) => (n - 1, *digit as $int + 5),
+                            _ => (n, *digit as $int),
+                        };
+
+                        n.checked_mul(5)?.checked_add(digit)
+                    })
+                    .with_context(|| {
+                        format!("{snafu} doesn't fit in {}", stringify!($int))
+                    })
+            }
+        }
+    )*};
+}
+
+impl_int_conversions!(i64, i128);
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    /// Adds digit by digit, carrying whenever a digit goes past ±2
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.digits.len().max(rhs.digits.len());
+        let mut digits = Vec::with_capacity(len + 1);
+        let mut carry = 0;
+
+        for k in 0..len {
+            let sum = self.digits.get(k).unwrap_or(&0) + rhs.digits.get(k).unwrap_or(&0) + carry;
+            let digit;
+            (carry, digit) = match sum {
+                3.. => (1, sum - 5),
+                ..-2 => (-1, sum + 5),
+                _ => (0, sum),
+            };
+            digits.push(digit);
+        }
+        digits.push(carry);
+
+        Snafu::from_digits(digits)
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for &Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Self::Output {
+        Snafu {
+            digits: self.digits.iter().map(|d| -d).collect(),
+        }
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |acc, n| &acc + n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: [(i64, &str); 16] = [
+        (0, "0"),
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ];
+
+    #[test]
+    fn conversions() {
+        for (n, s) in TABLE {
+            let snafu: Snafu = s.parse().unwrap();
+
+            assert_eq!(snafu, Snafu::from(n));
+            assert_eq!(snafu.to_string(), s);
+            assert_eq!(i64::try_from(&snafu).unwrap(), n);
+            assert_eq!(i128::try_from(&snafu).unwrap(), n as i128);
+            assert_eq!(i64::try_from(&-&snafu).unwrap(), -n);
+        }
+
+        for n in [i64::MIN, i64::MAX] {
+            assert_eq!(i64::try_from(&Snafu::from(n)).unwrap(), n);
+        }
+        for n in [i128::MIN, i128::MAX] {
+            assert_eq!(i128::try_from(&Snafu::from(n)).unwrap(), n);
+        }
+
+        assert!(i64::try_from(&Snafu::from(i64::MAX as i128 + 1)).is_err());
+        assert_eq!("00-".parse::<Snafu>().unwrap().to_string(), "-");
+        assert!("".parse::<Snafu>().is_err());
+        assert!("12a".parse::<Snafu>().is_err());
+    }
+
+    #[test]
+    fn addition() {
+        for a in -200..200_i64 {
+            for b in -200..200_i64 {
+                let sum = Snafu::from(a) + Snafu::from(b);
+
+                assert_eq!(i64::try_from(&sum).unwrap(), a + b, "{a} + {b}");
+                assert_eq!(sum.is_negative(), a + b < 0);
+            }
+        }
+    }
+}